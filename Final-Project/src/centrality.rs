@@ -0,0 +1,288 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::graph::Graph;
+
+
+impl<T: Eq + Hash + Clone> Graph<T> {
+    /// Iterative PageRank. Each node splits its rank equally among its
+    /// neighbors; isolated nodes spread theirs uniformly over the whole graph.
+    pub fn pagerank(&self, damping: f64, iterations: usize) -> HashMap<T, f64> {
+        let n = self.node_count();
+        if n == 0 {
+            return HashMap::new();
+        }
+
+        let mut ranks = vec![1.0 / n as f64; n];
+        for _ in 0..iterations {
+            let dangling: f64 = self
+                .adjacency
+                .iter()
+                .zip(&ranks)
+                .filter(|(neighbors, _)| neighbors.is_empty())
+                .map(|(_, rank)| rank)
+                .sum();
+            let base = (1.0 - damping) / n as f64 + damping * dangling / n as f64;
+
+            let mut next = vec![base; n];
+            for (neighbors, rank) in self.adjacency.iter().zip(&ranks) {
+                if neighbors.is_empty() {
+                    continue;
+                }
+                let share = damping * rank / neighbors.len() as f64;
+                for &neighbor in neighbors {
+                    next[neighbor as usize] += share;
+                }
+            }
+            ranks = next;
+        }
+
+        self.nodes.iter().cloned().zip(ranks).collect()
+    }
+
+
+    /// Eigenvector centrality by power iteration, L2-normalized. Iterates
+    /// with the adjacency matrix plus the identity, which has the same
+    /// leading eigenvector but also converges on bipartite graphs, where
+    /// plain power iteration oscillates. Stops once the L1 change between
+    /// steps drops below `tolerance`; `None` when that does not happen within
+    /// `iterations` steps.
+    pub fn eigenvector_centrality(&self, iterations: usize, tolerance: f64) -> Option<HashMap<T, f64>> {
+        let n = self.node_count();
+        if n == 0 {
+            return Some(HashMap::new());
+        }
+
+        let mut scores = vec![1.0 / (n as f64).sqrt(); n];
+        for _ in 0..iterations {
+            let mut next = scores.clone();
+            for (id, neighbors) in self.adjacency.iter().enumerate() {
+                for &neighbor in neighbors {
+                    next[id] += scores[neighbor as usize];
+                }
+            }
+            let norm = next.iter().map(|score| score * score).sum::<f64>().sqrt();
+            for score in next.iter_mut() {
+                *score /= norm;
+            }
+
+            let change: f64 = next.iter().zip(&scores).map(|(a, b)| (a - b).abs()).sum();
+            scores = next;
+            if change < tolerance {
+                return Some(self.nodes.iter().cloned().zip(scores).collect());
+            }
+        }
+        None
+    }
+
+
+    /// One Brandes pass from `source`: how much each node contributes to the
+    /// shortest paths that start at `source`.
+    pub(crate) fn dependencies_from(&self, source: u32) -> Vec<f64> {
+        let n = self.node_count();
+        let mut stack: Vec<u32> = Vec::new();
+        let mut predecessors: Vec<Vec<u32>> = vec![Vec::new(); n];
+        let mut path_counts = vec![0.0; n];
+        let mut distances: Vec<Option<usize>> = vec![None; n];
+        path_counts[source as usize] = 1.0;
+        distances[source as usize] = Some(0);
+
+        let mut queue = VecDeque::new();
+        queue.push_back(source);
+        while let Some(current) = queue.pop_front() {
+            stack.push(current);
+            let distance = distances[current as usize].unwrap();
+            for &neighbor in self.neighbor_ids(current) {
+                let neighbor_index = neighbor as usize;
+                if distances[neighbor_index].is_none() {
+                    distances[neighbor_index] = Some(distance + 1);
+                    queue.push_back(neighbor);
+                }
+                if distances[neighbor_index] == Some(distance + 1) {
+                    path_counts[neighbor_index] += path_counts[current as usize];
+                    predecessors[neighbor_index].push(current);
+                }
+            }
+        }
+
+        let mut dependencies = vec![0.0; n];
+        while let Some(node) = stack.pop() {
+            let node = node as usize;
+            for &predecessor in &predecessors[node] {
+                let predecessor = predecessor as usize;
+                let share = path_counts[predecessor] / path_counts[node] * (1.0 + dependencies[node]);
+                dependencies[predecessor] += share;
+            }
+        }
+        dependencies[source as usize] = 0.0;
+        dependencies
+    }
+
+
+    /// Betweenness centrality via Brandes' algorithm: for each node, the number
+    /// of shortest paths between other pairs that pass through it, with each
+    /// unordered pair counted once.
+    pub fn betweenness_centrality(&self) -> HashMap<T, f64> {
+        let mut centrality = vec![0.0; self.node_count()];
+        for source in 0..self.node_count() as u32 {
+            for (score, dependency) in centrality.iter_mut().zip(self.dependencies_from(source)) {
+                *score += dependency;
+            }
+        }
+
+        // Every unordered pair was visited from both of its endpoints.
+        self.nodes.iter().cloned().zip(centrality.into_iter().map(|score| score / 2.0)).collect()
+    }
+
+
+    /// `betweenness_centrality` divided by the number of pairs that exclude the
+    /// node, `(n-1)(n-2)/2`, so scores fall in `[0, 1]`.
+    pub fn normalized_betweenness_centrality(&self) -> HashMap<T, f64> {
+        let n = self.node_count();
+        let mut centrality = self.betweenness_centrality();
+        if n > 2 {
+            let pairs = ((n - 1) * (n - 2)) as f64 / 2.0;
+            for score in centrality.values_mut() {
+                *score /= pairs;
+            }
+        }
+        centrality
+    }
+
+
+    /// Closeness centrality with the Wasserman-Faust correction: a node that
+    /// reaches `r` of the other `n - 1` nodes at total distance `d` scores
+    /// `(r / (n - 1)) * (r / d)`, so nodes in small components are not
+    /// over-rated. Isolated nodes score 0.0.
+    pub fn closeness_centrality(&self) -> HashMap<T, f64> {
+        (0..self.node_count() as u32).map(|id| (self.label(id).clone(), self.closeness_of(id))).collect()
+    }
+
+
+    pub(crate) fn closeness_of(&self, id: u32) -> f64 {
+        let distances: Vec<usize> = self.bfs_distances(id).into_iter().flatten().collect();
+        let reachable = distances.len() - 1;
+        if reachable == 0 {
+            return 0.0;
+        }
+
+        let total_distance: usize = distances.iter().sum();
+        let reachable = reachable as f64;
+        (reachable / (self.node_count() - 1) as f64) * (reachable / total_distance as f64)
+    }
+
+
+    /// Harmonic centrality: the sum of `1 / distance` to every other node,
+    /// with unreachable nodes adding 0.0, so it stays meaningful on
+    /// disconnected graphs. One BFS per node.
+    pub fn harmonic_centrality(&self) -> HashMap<T, f64> {
+        (0..self.node_count() as u32)
+            .map(|id| {
+                let score: f64 = self
+                    .bfs_distances(id)
+                    .into_iter()
+                    .flatten()
+                    .filter(|&distance| distance > 0)
+                    .map(|distance| 1.0 / distance as f64)
+                    .sum();
+                (self.label(id).clone(), score)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pagerank() {
+        let mut graph = Graph::new();
+        graph.add_edge("Hub".to_string(), "A".to_string());
+        graph.add_edge("Hub".to_string(), "B".to_string());
+        graph.add_edge("Hub".to_string(), "C".to_string());
+        graph.add_edge("D".to_string(), "E".to_string());
+        graph.remove_edge(&"D".to_string(), &"E".to_string());
+
+        let ranks = graph.pagerank(0.85, 50);
+        let total: f64 = ranks.values().sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        assert!(ranks["Hub"] > ranks["A"]);
+        assert!((ranks["A"] - ranks["B"]).abs() < 1e-12);
+        assert!((ranks["D"] - ranks["E"]).abs() < 1e-12);
+        assert!(Graph::<String>::new().pagerank(0.85, 10).is_empty());
+    }
+
+    #[test]
+    fn test_betweenness_centrality() {
+        // A - B - C - D with a second route B - E - D.
+        let mut graph = Graph::new();
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("B".to_string(), "C".to_string());
+        graph.add_edge("C".to_string(), "D".to_string());
+        graph.add_edge("B".to_string(), "E".to_string());
+        graph.add_edge("E".to_string(), "D".to_string());
+
+        let centrality = graph.betweenness_centrality();
+        // B carries A-C, A-D, A-E fully and half of C-E.
+        assert!((centrality["B"] - 3.5).abs() < 1e-12);
+        assert!((centrality["C"] - 1.0).abs() < 1e-12);
+        assert!((centrality["E"] - 1.0).abs() < 1e-12);
+        assert!((centrality["D"] - 0.5).abs() < 1e-12);
+        assert_eq!(centrality["A"], 0.0);
+
+        let normalized = graph.normalized_betweenness_centrality();
+        assert!((normalized["B"] - 3.5 / 6.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_closeness_centrality() {
+        let mut graph = Graph::new();
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("B".to_string(), "C".to_string());
+        graph.add_edge("X".to_string(), "Y".to_string());
+        graph.add_edge("Z".to_string(), "Z".to_string());
+        graph.remove_self_loops();
+
+        let closeness = graph.closeness_centrality();
+        // B reaches 2 of 5 other nodes at total distance 2.
+        assert!((closeness["B"] - (2.0 / 5.0) * (2.0 / 2.0)).abs() < 1e-12);
+        assert!((closeness["A"] - (2.0 / 5.0) * (2.0 / 3.0)).abs() < 1e-12);
+        // Without the correction X would score a perfect 1.0.
+        assert!((closeness["X"] - 1.0 / 5.0).abs() < 1e-12);
+        assert_eq!(closeness["Z"], 0.0);
+    }
+
+    #[test]
+    fn test_harmonic_centrality() {
+        let mut graph = Graph::new();
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("B".to_string(), "C".to_string());
+        graph.add_edge("C".to_string(), "D".to_string());
+        graph.add_edge("X".to_string(), "X".to_string());
+
+        let harmonic = graph.harmonic_centrality();
+        assert!((harmonic["A"] - (1.0 + 1.0 / 2.0 + 1.0 / 3.0)).abs() < 1e-12);
+        assert!((harmonic["B"] - (1.0 + 1.0 + 1.0 / 2.0)).abs() < 1e-12);
+        assert_eq!(harmonic["C"], harmonic["B"]);
+        assert_eq!(harmonic["X"], 0.0);
+    }
+
+    #[test]
+    fn test_eigenvector_centrality() {
+        // A star is bipartite; its leading eigenvector has the hub at 1/sqrt(2).
+        let mut star = Graph::new();
+        for leaf in ["A", "B", "C"] {
+            star.add_edge("Hub".to_string(), leaf.to_string());
+        }
+
+        let centrality = star.eigenvector_centrality(100, 1e-12).unwrap();
+        assert!((centrality["Hub"] - 0.5f64.sqrt()).abs() < 1e-9);
+        assert!((centrality["A"] - (1.0f64 / 6.0).sqrt()).abs() < 1e-9);
+        let norm: f64 = centrality.values().map(|score| score * score).sum();
+        assert!((norm - 1.0).abs() < 1e-12);
+
+        assert!(star.eigenvector_centrality(1, 1e-12).is_none());
+        assert_eq!(Graph::<String>::new().eigenvector_centrality(0, 1e-6), Some(HashMap::new()));
+    }
+}
@@ -0,0 +1,195 @@
+use std::hash::Hash;
+
+use crate::graph::Graph;
+
+
+impl<T: Eq + Hash + Clone> Graph<T> {
+    /// Neighbors of `id` other than itself, so self-loops never count as a link.
+    fn proper_neighbors(&self, id: u32) -> Vec<u32> {
+        self.neighbor_ids(id).iter().copied().filter(|&neighbor| neighbor != id).collect()
+    }
+
+
+    /// Number of edges between pairs of nodes in `neighbors`.
+    fn neighbor_links(&self, neighbors: &[u32]) -> usize {
+        let mut links = 0;
+        for (i, &first) in neighbors.iter().enumerate() {
+            let first_neighbors = self.neighbor_ids(first);
+            for second in &neighbors[i + 1..] {
+                if first_neighbors.contains(second) {
+                    links += 1;
+                }
+            }
+        }
+        links
+    }
+
+
+    fn clustering_of(&self, id: u32) -> f64 {
+        let neighbors = self.proper_neighbors(id);
+        let degree = neighbors.len();
+        if degree < 2 {
+            return 0.0;
+        }
+
+        let possible = degree * (degree - 1) / 2;
+        self.neighbor_links(&neighbors) as f64 / possible as f64
+    }
+
+
+    /// Fraction of the possible edges among `node`'s neighbors that exist.
+    ///
+    /// Nodes with fewer than two neighbors (or unknown nodes) score 0.0.
+    pub fn local_clustering_coefficient(&self, node: &T) -> f64 {
+        self.id(node).map_or(0.0, |id| self.clustering_of(id))
+    }
+
+
+    /// Mean of the local clustering coefficients, with degree < 2 nodes counted as 0.0.
+    pub fn average_clustering_coefficient(&self) -> f64 {
+        if self.node_count() == 0 {
+            return 0.0;
+        }
+
+        let total: f64 = (0..self.node_count() as u32).map(|id| self.clustering_of(id)).sum();
+        total / self.node_count() as f64
+    }
+
+
+    /// Global clustering: 3 × triangles / connected triples, or 0.0 with no triples.
+    pub fn transitivity(&self) -> f64 {
+        let mut closed_triples = 0;
+        let mut triples = 0;
+
+        for id in 0..self.node_count() as u32 {
+            let neighbors = self.proper_neighbors(id);
+            let degree = neighbors.len();
+            if degree < 2 {
+                continue;
+            }
+            triples += degree * (degree - 1) / 2;
+            closed_triples += self.neighbor_links(&neighbors);
+        }
+
+        if triples == 0 {
+            return 0.0;
+        }
+        closed_triples as f64 / triples as f64
+    }
+
+
+    pub fn triangles_through_node(&self, node: &T) -> usize {
+        match self.id(node) {
+            Some(id) => self.neighbor_links(&self.proper_neighbors(id)),
+            None => 0,
+        }
+    }
+
+
+    /// Number of distinct triangles, each counted once via increasing node ids.
+    pub fn triangle_count(&self) -> usize {
+        let mut count = 0;
+
+        for (u, u_neighbors) in self.adjacency.iter().enumerate() {
+            let u = u as u32;
+            for &v in u_neighbors.iter().filter(|&&v| v > u) {
+                let v_neighbors = self.neighbor_ids(v);
+                count += u_neighbors.iter().filter(|&&w| w > v && v_neighbors.contains(&w)).count();
+            }
+        }
+
+        count
+    }
+}
+
+
+impl<T: Eq + Hash + Clone + Ord> Graph<T> {
+    /// Every triangle once, as its three nodes in sorted order, with the
+    /// list itself sorted. Self-loops never form a triangle. A dense graph
+    /// can hold up to n³/6 triangles, so the output may be far larger than
+    /// the graph; `triangle_count` is the cheap alternative.
+    pub fn list_triangles(&self) -> Vec<(T, T, T)> {
+        let mut triangles = Vec::new();
+        for (u, u_neighbors) in self.adjacency.iter().enumerate() {
+            let u = u as u32;
+            for &v in u_neighbors.iter().filter(|&&v| v > u) {
+                let v_neighbors = self.neighbor_ids(v);
+                for &w in u_neighbors.iter().filter(|&&w| w > v && v_neighbors.contains(&w)) {
+                    let mut corners = [self.label(u), self.label(v), self.label(w)];
+                    corners.sort();
+                    triangles.push((corners[0].clone(), corners[1].clone(), corners[2].clone()));
+                }
+            }
+        }
+        triangles.sort();
+        triangles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_local_clustering_coefficient() {
+        let mut graph = Graph::new();
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("A".to_string(), "C".to_string());
+        graph.add_edge("A".to_string(), "D".to_string());
+        graph.add_edge("B".to_string(), "C".to_string());
+
+        assert!((graph.local_clustering_coefficient(&"A".to_string()) - 1.0 / 3.0).abs() < 1e-12);
+        assert_eq!(graph.local_clustering_coefficient(&"B".to_string()), 1.0);
+        assert_eq!(graph.local_clustering_coefficient(&"D".to_string()), 0.0);
+        assert_eq!(graph.local_clustering_coefficient(&"Z".to_string()), 0.0);
+    }
+
+    #[test]
+    fn test_average_clustering_and_transitivity() {
+        let mut graph = Graph::new();
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("A".to_string(), "C".to_string());
+        graph.add_edge("A".to_string(), "D".to_string());
+        graph.add_edge("B".to_string(), "C".to_string());
+
+        // Local coefficients: A = 1/3, B = C = 1, D = 0.
+        assert!((graph.average_clustering_coefficient() - (7.0 / 3.0) / 4.0).abs() < 1e-12);
+        // One triangle over five connected triples.
+        assert!((graph.transitivity() - 3.0 / 5.0).abs() < 1e-12);
+
+        assert_eq!(Graph::<String>::new().average_clustering_coefficient(), 0.0);
+        assert_eq!(Graph::<String>::new().transitivity(), 0.0);
+    }
+
+    #[test]
+    fn test_triangle_count() {
+        let mut graph = Graph::new();
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("A".to_string(), "C".to_string());
+        graph.add_edge("B".to_string(), "C".to_string());
+        graph.add_edge("B".to_string(), "D".to_string());
+        graph.add_edge("C".to_string(), "D".to_string());
+        graph.add_edge("D".to_string(), "D".to_string());
+
+        assert_eq!(graph.triangle_count(), 2);
+        assert_eq!(graph.triangles_through_node(&"A".to_string()), 1);
+        assert_eq!(graph.triangles_through_node(&"B".to_string()), 2);
+        assert_eq!(graph.triangles_through_node(&"D".to_string()), 1);
+        assert_eq!(graph.triangles_through_node(&"Z".to_string()), 0);
+    }
+
+    #[test]
+    fn test_list_triangles() {
+        let mut graph = Graph::new();
+        for (a, b) in [("D", "B"), ("C", "D"), ("B", "C"), ("A", "C"), ("B", "A"), ("D", "D"), ("D", "E")] {
+            graph.add_edge(a.to_string(), b.to_string());
+        }
+
+        let triangles = graph.list_triangles();
+        let expected =
+            [("A", "B", "C"), ("B", "C", "D")].map(|(a, b, c)| (a.to_string(), b.to_string(), c.to_string()));
+        assert_eq!(triangles, expected);
+        assert_eq!(triangles.len(), graph.triangle_count());
+        assert!(Graph::<String>::new().list_triangles().is_empty());
+    }
+}
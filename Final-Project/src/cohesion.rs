@@ -0,0 +1,331 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::graph::{weight_key, Graph};
+
+
+impl<T: Eq + Hash + Clone> Graph<T> {
+    /// Degree ignoring any self-loop, which cannot help a node stay in a core.
+    fn degree_without_loop(&self, id: u32) -> usize {
+        let neighbors = self.neighbor_ids(id);
+        neighbors.len() - usize::from(neighbors.contains(&id))
+    }
+
+
+    /// The k-core: what remains after repeatedly deleting nodes with fewer
+    /// than `k` neighbors. Empty when the whole graph is stripped away.
+    pub fn k_core(&self, k: usize) -> Graph<T> {
+        let mut alive = vec![true; self.node_count()];
+        let mut degrees: Vec<usize> = (0..self.node_count() as u32).map(|id| self.degree_without_loop(id)).collect();
+        let mut weak: Vec<u32> = (0..self.node_count() as u32).filter(|&id| degrees[id as usize] < k).collect();
+        for &id in &weak {
+            alive[id as usize] = false;
+        }
+
+        while let Some(id) = weak.pop() {
+            for &neighbor in self.neighbor_ids(id) {
+                if neighbor != id && alive[neighbor as usize] {
+                    degrees[neighbor as usize] -= 1;
+                    if degrees[neighbor as usize] < k {
+                        alive[neighbor as usize] = false;
+                        weak.push(neighbor);
+                    }
+                }
+            }
+        }
+
+        let kept: Vec<u32> = (0..self.node_count() as u32).filter(|&id| alive[id as usize]).collect();
+        self.subgraph_of_ids(&kept)
+    }
+
+
+    /// The k-truss: the edges left after repeatedly deleting every edge that
+    /// lies in fewer than `k - 2` triangles, and the nodes they touch.
+    /// Self-loops never survive, and nodes left without edges are dropped.
+    pub fn k_truss(&self, k: usize) -> Graph<T> {
+        let needed = k.saturating_sub(2);
+        let mut alive: Vec<HashSet<u32>> = (0..self.node_count() as u32)
+            .map(|id| self.neighbor_ids(id).iter().copied().filter(|&neighbor| neighbor != id).collect())
+            .collect();
+
+        let mut support: HashMap<(u32, u32), usize> = HashMap::new();
+        for (id, neighbors) in alive.iter().enumerate() {
+            for &neighbor in neighbors.iter().filter(|&&neighbor| neighbor as usize > id) {
+                let shared = neighbors.intersection(&alive[neighbor as usize]).count();
+                support.insert((id as u32, neighbor), shared);
+            }
+        }
+
+        let mut weak: Vec<(u32, u32)> =
+            support.iter().filter(|&(_, &shared)| shared < needed).map(|(&edge, _)| edge).collect();
+        while let Some((a, b)) = weak.pop() {
+            if !alive[a as usize].remove(&b) {
+                continue;
+            }
+            alive[b as usize].remove(&a);
+            let shared: Vec<u32> = alive[a as usize].intersection(&alive[b as usize]).copied().collect();
+            for third in shared {
+                for edge in [weight_key(a, third), weight_key(b, third)] {
+                    let count = support.get_mut(&edge).unwrap();
+                    *count -= 1;
+                    if *count + 1 == needed {
+                        weak.push(edge);
+                    }
+                }
+            }
+        }
+
+        let kept: Vec<u32> = (0..self.node_count() as u32).filter(|&id| !alive[id as usize].is_empty()).collect();
+        let mut truss = self.subgraph_of_ids(&kept);
+        for &id in &kept {
+            for &neighbor in self.neighbor_ids(id) {
+                if !alive[id as usize].contains(&neighbor) {
+                    truss.remove_edge(self.label(id), self.label(neighbor));
+                }
+            }
+        }
+        truss
+    }
+
+
+    /// For each node, the largest `k` such that it belongs to the k-core.
+    pub fn core_number(&self) -> HashMap<T, usize> {
+        let mut degrees: Vec<Option<usize>> =
+            (0..self.node_count() as u32).map(|id| Some(self.degree_without_loop(id))).collect();
+        let mut queue: BTreeSet<(usize, u32)> =
+            degrees.iter().enumerate().map(|(id, degree)| (degree.unwrap(), id as u32)).collect();
+        let mut cores = HashMap::new();
+        let mut current_core = 0;
+
+        while let Some((degree, id)) = queue.pop_first() {
+            current_core = current_core.max(degree);
+            cores.insert(self.label(id).clone(), current_core);
+            degrees[id as usize] = None;
+            for &neighbor in self.neighbor_ids(id) {
+                if let Some(neighbor_degree) = degrees[neighbor as usize].as_mut() {
+                    queue.remove(&(*neighbor_degree, neighbor));
+                    *neighbor_degree -= 1;
+                    queue.insert((*neighbor_degree, neighbor));
+                }
+            }
+        }
+
+        cores
+    }
+
+
+    /// Bron-Kerbosch with pivoting: extends `clique` by nodes of `candidates`,
+    /// pushing every maximal clique found onto `cliques`.
+    fn extend_cliques(
+        &self,
+        clique: &mut Vec<u32>,
+        candidates: HashSet<u32>,
+        mut excluded: HashSet<u32>,
+        cliques: &mut Vec<Vec<u32>>,
+    ) {
+        if candidates.is_empty() {
+            if excluded.is_empty() {
+                cliques.push(clique.clone());
+            }
+            return;
+        }
+
+        let proper = |id: u32| self.neighbor_ids(id).iter().copied().filter(move |&neighbor| neighbor != id);
+        let pivot = candidates
+            .union(&excluded)
+            .copied()
+            .max_by_key(|&id| proper(id).filter(|neighbor| candidates.contains(neighbor)).count())
+            .unwrap();
+        let pivot_neighbors: HashSet<u32> = proper(pivot).collect();
+
+        let mut remaining = candidates.clone();
+        for id in candidates.into_iter().filter(|id| !pivot_neighbors.contains(id)) {
+            let neighbors: HashSet<u32> = proper(id).collect();
+            clique.push(id);
+            self.extend_cliques(
+                clique,
+                remaining.intersection(&neighbors).copied().collect(),
+                excluded.intersection(&neighbors).copied().collect(),
+                cliques,
+            );
+            clique.pop();
+            remaining.remove(&id);
+            excluded.insert(id);
+        }
+    }
+}
+
+
+impl<T: Eq + Hash + Clone + Ord> Graph<T> {
+    /// Repeatedly removes a node of minimum remaining degree, ties to the
+    /// smallest label. Returns the removal order and the largest degree seen
+    /// at removal time. Self-loops are ignored.
+    fn degeneracy_order_ids(&self) -> (Vec<u32>, usize) {
+        let mut rank = vec![0; self.node_count()];
+        for (position, id) in self.sorted_ids().into_iter().enumerate() {
+            rank[id as usize] = position;
+        }
+        let mut degrees: Vec<Option<usize>> =
+            (0..self.node_count() as u32).map(|id| Some(self.degree_without_loop(id))).collect();
+        let mut queue: BTreeSet<(usize, usize, u32)> =
+            (0..self.node_count() as u32).map(|id| (degrees[id as usize].unwrap(), rank[id as usize], id)).collect();
+
+        let mut order = Vec::with_capacity(self.node_count());
+        let mut degeneracy = 0;
+        while let Some((degree, _, id)) = queue.pop_first() {
+            degeneracy = degeneracy.max(degree);
+            order.push(id);
+            degrees[id as usize] = None;
+            for &neighbor in self.neighbor_ids(id) {
+                if let Some(neighbor_degree) = degrees[neighbor as usize].as_mut() {
+                    queue.remove(&(*neighbor_degree, rank[neighbor as usize], neighbor));
+                    *neighbor_degree -= 1;
+                    queue.insert((*neighbor_degree, rank[neighbor as usize], neighbor));
+                }
+            }
+        }
+        (order, degeneracy)
+    }
+
+
+    /// Degeneracy ordering: nodes in the order they are removed when the
+    /// node of lowest remaining degree is deleted each time, ties broken by
+    /// name. Every node has at most `degeneracy` neighbors later in the order.
+    pub fn degeneracy_ordering(&self) -> Vec<T> {
+        self.degeneracy_order_ids().0.into_iter().map(|id| self.label(id).clone()).collect()
+    }
+
+
+    /// The largest `k` with a non-empty k-core, i.e. the highest value in
+    /// `core_number`; 0 for a graph without edges.
+    pub fn degeneracy(&self) -> usize {
+        self.degeneracy_order_ids().1
+    }
+
+
+    /// Every maximal clique, found with Bron-Kerbosch and pivoting. Cliques
+    /// are ordered by their sorted members; self-loops are ignored and an
+    /// isolated node is a clique of one.
+    ///
+    /// The number of maximal cliques can grow exponentially with the node
+    /// count (up to 3^(n/3)), so this is only practical on sparse graphs or
+    /// small dense ones.
+    pub fn maximal_cliques(&self) -> Vec<HashSet<T>> {
+        let mut cliques = Vec::new();
+        self.extend_cliques(&mut Vec::new(), (0..self.node_count() as u32).collect(), HashSet::new(), &mut cliques);
+
+        let mut cliques: Vec<Vec<&T>> = cliques
+            .into_iter()
+            .map(|clique| {
+                let mut members: Vec<&T> = clique.into_iter().map(|id| self.label(id)).collect();
+                members.sort();
+                members
+            })
+            .collect();
+        cliques.sort();
+        cliques.into_iter().map(|clique| clique.into_iter().cloned().collect()).collect()
+    }
+
+
+    /// Size of the largest clique; 0 for an empty graph. Shares the cost of
+    /// `maximal_cliques`.
+    pub fn max_clique_size(&self) -> usize {
+        self.maximal_cliques().iter().map(HashSet::len).max().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle_with_tail() -> Graph {
+        let mut graph = Graph::new();
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("B".to_string(), "C".to_string());
+        graph.add_edge("C".to_string(), "A".to_string());
+        graph.add_edge("C".to_string(), "D".to_string());
+        graph.add_edge("D".to_string(), "E".to_string());
+        graph.add_edge("E".to_string(), "E".to_string());
+        graph
+    }
+
+    #[test]
+    fn test_k_core() {
+        let graph = triangle_with_tail();
+
+        let two_core = graph.k_core(2);
+        assert_eq!(two_core.node_count(), 3);
+        assert!(["A", "B", "C"].iter().all(|node| two_core.contains_node(&node.to_string())));
+        assert_eq!(two_core.edge_count(), 3);
+
+        assert_eq!(graph.k_core(3).node_count(), 0);
+        assert_eq!(graph.k_core(0).node_count(), 5);
+    }
+
+    #[test]
+    fn test_core_number() {
+        let cores = triangle_with_tail().core_number();
+        assert_eq!(cores["A"], 2);
+        assert_eq!(cores["C"], 2);
+        assert_eq!(cores["D"], 1);
+        assert_eq!(cores["E"], 1);
+        assert!(Graph::<String>::new().core_number().is_empty());
+    }
+
+    #[test]
+    fn test_maximal_cliques() {
+        let mut graph = triangle_with_tail();
+        for (a, b) in [("B", "F"), ("C", "F"), ("A", "F")] {
+            graph.add_edge(a.to_string(), b.to_string());
+        }
+        graph.add_edge("Z".to_string(), "Z".to_string());
+
+        let cliques: Vec<Vec<String>> = graph
+            .maximal_cliques()
+            .into_iter()
+            .map(|clique| {
+                let mut members: Vec<String> = clique.into_iter().collect();
+                members.sort();
+                members
+            })
+            .collect();
+        let expected: Vec<Vec<&str>> = vec![vec!["A", "B", "C", "F"], vec!["C", "D"], vec!["D", "E"], vec!["Z"]];
+        assert_eq!(cliques, expected);
+        assert_eq!(graph.max_clique_size(), 4);
+        assert_eq!(Graph::<String>::new().max_clique_size(), 0);
+    }
+
+    #[test]
+    fn test_k_truss() {
+        // K4 on A-D, the triangle D-E-F hanging off it, and the tail F-G.
+        let mut graph = Graph::new();
+        for (a, b) in [("A", "B"), ("A", "C"), ("A", "D"), ("B", "C"), ("B", "D"), ("C", "D")] {
+            graph.add_edge(a.to_string(), b.to_string());
+        }
+        for (a, b) in [("D", "E"), ("E", "F"), ("F", "D"), ("F", "G"), ("G", "G")] {
+            graph.add_edge(a.to_string(), b.to_string());
+        }
+
+        let four_truss = graph.k_truss(4);
+        assert_eq!((four_truss.node_count(), four_truss.edge_count()), (4, 6));
+        assert!(!four_truss.contains_node(&"E".to_string()));
+
+        let three_truss = graph.k_truss(3);
+        assert_eq!((three_truss.node_count(), three_truss.edge_count()), (6, 9));
+        assert!(!three_truss.contains_node(&"G".to_string()));
+
+        assert_eq!(graph.k_truss(2).edge_count(), 10);
+        assert_eq!(graph.k_truss(5).node_count(), 0);
+    }
+
+    #[test]
+    fn test_degeneracy_ordering() {
+        let graph = triangle_with_tail();
+        // E and D peel off first, then the triangle A-B-C in name order.
+        assert_eq!(graph.degeneracy_ordering(), vec!["E", "D", "A", "B", "C"]);
+        assert_eq!(graph.degeneracy(), 2);
+        assert_eq!(graph.degeneracy(), *graph.core_number().values().max().unwrap());
+        assert!(Graph::<String>::new().degeneracy_ordering().is_empty());
+        assert_eq!(Graph::<String>::new().degeneracy(), 0);
+    }
+}
@@ -0,0 +1,323 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::graph::Graph;
+use crate::rng::Rng;
+
+
+/// Weighted graph over dense indices used by Louvain; each aggregation level
+/// collapses communities into single nodes whose internal weight is kept in `loops`.
+struct WeightedLevel {
+    adjacency: Vec<HashMap<usize, f64>>,
+    loops: Vec<f64>,
+}
+
+impl WeightedLevel {
+    fn strength(&self, node: usize) -> f64 {
+        self.adjacency[node].values().sum::<f64>() + 2.0 * self.loops[node]
+    }
+
+
+    /// Local-moving phase. Returns each node's community and whether any node moved.
+    fn move_nodes(&self, total_weight: f64) -> (Vec<usize>, bool) {
+        let n = self.adjacency.len();
+        let strengths: Vec<f64> = (0..n).map(|node| self.strength(node)).collect();
+        let mut community: Vec<usize> = (0..n).collect();
+        let mut community_totals = strengths.clone();
+        let mut moved_any = false;
+
+        loop {
+            let mut moved = false;
+            for node in 0..n {
+                let current = community[node];
+                community_totals[current] -= strengths[node];
+
+                let mut links: HashMap<usize, f64> = HashMap::new();
+                for (&neighbor, &weight) in &self.adjacency[node] {
+                    *links.entry(community[neighbor]).or_insert(0.0) += weight;
+                }
+                let gain = |candidate: usize, link: f64| link - community_totals[candidate] * strengths[node] / total_weight;
+
+                let mut best = current;
+                let mut best_gain = gain(current, links.get(&current).copied().unwrap_or(0.0));
+                let mut candidates: Vec<(usize, f64)> = links.into_iter().collect();
+                candidates.sort_by_key(|&(candidate, _)| candidate);
+                for (candidate, link) in candidates {
+                    let candidate_gain = gain(candidate, link);
+                    if candidate_gain > best_gain + 1e-12 {
+                        best = candidate;
+                        best_gain = candidate_gain;
+                    }
+                }
+
+                community_totals[best] += strengths[node];
+                if best != current {
+                    community[node] = best;
+                    moved = true;
+                    moved_any = true;
+                }
+            }
+            if !moved {
+                break;
+            }
+        }
+
+        (community, moved_any)
+    }
+
+
+    /// Collapses each community into one node. `community` is relabelled to dense ids in place.
+    fn aggregate(&self, community: &mut [usize]) -> WeightedLevel {
+        let mut relabel: HashMap<usize, usize> = HashMap::new();
+        for label in community.iter_mut() {
+            let next = relabel.len();
+            *label = *relabel.entry(*label).or_insert(next);
+        }
+
+        let size = relabel.len();
+        let mut adjacency = vec![HashMap::new(); size];
+        let mut loops = vec![0.0; size];
+        for (node, neighbors) in self.adjacency.iter().enumerate() {
+            let from = community[node];
+            loops[from] += self.loops[node];
+            for (&neighbor, &weight) in neighbors {
+                let to = community[neighbor];
+                if from == to {
+                    // Each internal edge is seen from both endpoints.
+                    loops[from] += weight / 2.0;
+                } else {
+                    *adjacency[from].entry(to).or_insert(0.0) += weight;
+                }
+            }
+        }
+
+        WeightedLevel { adjacency, loops }
+    }
+}
+
+
+impl<T: Eq + Hash + Clone> Graph<T> {
+    /// Newman-Girvan modularity of `communities`, any node-to-label map such
+    /// as hand labels or the output of `louvain_communities`. Nodes missing
+    /// from the map are treated as singleton communities, names in the map
+    /// but not in the graph are ignored, and self-loops are ignored; a graph
+    /// without edges scores 0.0.
+    pub fn modularity(&self, communities: &HashMap<T, usize>) -> f64 {
+        let labels: Vec<Option<usize>> = self.nodes.iter().map(|node| communities.get(node).copied()).collect();
+        let mut internal_edges: HashMap<Option<usize>, f64> = HashMap::new();
+        let mut degree_totals: HashMap<Option<usize>, f64> = HashMap::new();
+        let mut edges = 0.0;
+
+        for (id, neighbors) in self.adjacency.iter().enumerate() {
+            let label = labels[id];
+            for &neighbor in neighbors.iter().filter(|&&neighbor| neighbor as usize != id) {
+                edges += 0.5;
+                *degree_totals.entry(label).or_insert(0.0) += 1.0;
+                if label.is_some() && labels[neighbor as usize] == label {
+                    *internal_edges.entry(label).or_insert(0.0) += 0.5;
+                }
+            }
+        }
+        if edges == 0.0 {
+            return 0.0;
+        }
+
+        let internal: f64 = internal_edges.values().sum::<f64>() / edges;
+        let mut expected: f64 = degree_totals
+            .iter()
+            .filter(|(label, _)| label.is_some())
+            .map(|(_, total)| (total / (2.0 * edges)).powi(2))
+            .sum();
+        // Unlabelled nodes are singletons, so each contributes only its own degree.
+        for (id, neighbors) in self.adjacency.iter().enumerate() {
+            if labels[id].is_none() {
+                let degree = neighbors.iter().filter(|&&neighbor| neighbor as usize != id).count() as f64;
+                expected += (degree / (2.0 * edges)).powi(2);
+            }
+        }
+
+        internal - expected
+    }
+}
+
+
+impl<T: Eq + Hash + Clone + Ord> Graph<T> {
+    /// Louvain community detection. Nodes are processed in sorted order and
+    /// community ids are numbered by their smallest member, so the result is
+    /// deterministic. Self-loops are ignored.
+    pub fn louvain_communities(&self) -> HashMap<T, usize> {
+        let nodes = self.sorted_ids();
+        let mut index = vec![0; nodes.len()];
+        for (i, &id) in nodes.iter().enumerate() {
+            index[id as usize] = i;
+        }
+
+        let mut level = WeightedLevel {
+            adjacency: nodes
+                .iter()
+                .map(|&id| {
+                    self.neighbor_ids(id)
+                        .iter()
+                        .filter(|&&neighbor| neighbor != id)
+                        .map(|&neighbor| (index[neighbor as usize], 1.0))
+                        .collect()
+                })
+                .collect(),
+            loops: vec![0.0; nodes.len()],
+        };
+        let total_weight: f64 = (0..nodes.len()).map(|node| level.strength(node)).sum();
+
+        let mut membership: Vec<usize> = (0..nodes.len()).collect();
+        if total_weight > 0.0 {
+            loop {
+                let (mut community, moved) = level.move_nodes(total_weight);
+                if !moved {
+                    break;
+                }
+                level = level.aggregate(&mut community);
+                for label in membership.iter_mut() {
+                    *label = community[*label];
+                }
+            }
+        }
+
+        self.renumber_communities(&nodes, membership)
+    }
+
+
+    /// Asynchronous label propagation: every node starts in its own
+    /// community, then, in a shuffled order each round, adopts the label most
+    /// common among its neighbors. It keeps its own label when that is among
+    /// the most common, and other ties are broken at random. Stops after a
+    /// round with no change or after `max_iterations` rounds. The same seed
+    /// always gives the same partition, and community ids are numbered by
+    /// their smallest member as in `louvain_communities`. Self-loops are
+    /// ignored.
+    pub fn label_propagation(&self, max_iterations: usize, seed: u64) -> HashMap<T, usize> {
+        let nodes = self.sorted_ids();
+        let mut index = vec![0; nodes.len()];
+        for (i, &id) in nodes.iter().enumerate() {
+            index[id as usize] = i;
+        }
+
+        let mut rng = Rng::new(seed);
+        let mut membership: Vec<usize> = (0..nodes.len()).collect();
+        let mut order: Vec<usize> = (0..nodes.len()).collect();
+        for _ in 0..max_iterations {
+            rng.shuffle(&mut order);
+            let mut changed = false;
+            for &node in &order {
+                let id = nodes[node];
+                let mut counts: HashMap<usize, usize> = HashMap::new();
+                for &neighbor in self.neighbor_ids(id).iter().filter(|&&neighbor| neighbor != id) {
+                    *counts.entry(membership[index[neighbor as usize]]).or_insert(0) += 1;
+                }
+                let Some(&most) = counts.values().max() else {
+                    continue;
+                };
+                if counts.get(&membership[node]) == Some(&most) {
+                    continue;
+                }
+
+                let mut best: Vec<usize> =
+                    counts.into_iter().filter(|&(_, count)| count == most).map(|(label, _)| label).collect();
+                best.sort_unstable();
+                membership[node] = best[rng.below(best.len())];
+                changed = true;
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        self.renumber_communities(&nodes, membership)
+    }
+
+
+    /// Maps each node of `nodes` (sorted ids) to its community, renumbered so
+    /// ids follow the sorted order of each community's first member.
+    fn renumber_communities(&self, nodes: &[u32], membership: Vec<usize>) -> HashMap<T, usize> {
+        let mut renumber: HashMap<usize, usize> = HashMap::new();
+        nodes
+            .iter()
+            .zip(membership)
+            .map(|(&id, label)| {
+                let next = renumber.len();
+                (self.label(id).clone(), *renumber.entry(label).or_insert(next))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn two_triangles() -> Graph {
+        let mut graph = Graph::new();
+        for (a, b) in [("A", "B"), ("B", "C"), ("C", "A"), ("D", "E"), ("E", "F"), ("F", "D"), ("C", "D")] {
+            graph.add_edge(a.to_string(), b.to_string());
+        }
+        graph
+    }
+
+    #[test]
+    fn test_modularity() {
+        let graph = two_triangles();
+        let split: HashMap<String, usize> =
+            ["A", "B", "C", "D", "E", "F"].iter().enumerate().map(|(i, node)| (node.to_string(), i / 3)).collect();
+        // Each side holds 3 of 7 edges and half of the degree.
+        let expected = 2.0 * (3.0 / 7.0 - 0.25);
+        assert!((graph.modularity(&split) - expected).abs() < 1e-12);
+
+        let together: HashMap<String, usize> = split.keys().map(|node| (node.clone(), 0)).collect();
+        assert!(graph.modularity(&together).abs() < 1e-12);
+        assert_eq!(Graph::<String>::new().modularity(&HashMap::new()), 0.0);
+    }
+
+    #[test]
+    fn test_modularity_of_partial_partition() {
+        let graph = two_triangles();
+        let partial: HashMap<String, usize> =
+            ["A", "B", "C", "Q"].iter().map(|node| (node.to_string(), 5)).collect();
+        // D, E and F score as three singletons with degrees 3, 2 and 2.
+        let expected = 3.0 / 7.0 - 0.25 - (9.0 + 4.0 + 4.0) / 196.0;
+        assert!((graph.modularity(&partial) - expected).abs() < 1e-12);
+        assert!(graph.modularity(&graph.louvain_communities()) > graph.modularity(&partial));
+    }
+
+    #[test]
+    fn test_louvain_communities() {
+        let graph = two_triangles();
+        let communities = graph.louvain_communities();
+
+        assert_eq!(communities["A"], 0);
+        assert_eq!(communities["B"], 0);
+        assert_eq!(communities["C"], 0);
+        assert_eq!(communities["D"], 1);
+        assert_eq!(communities["E"], 1);
+        assert_eq!(communities["F"], 1);
+        assert_eq!(graph.louvain_communities(), communities);
+    }
+
+    #[test]
+    fn test_label_propagation() {
+        let mut graph = Graph::new();
+        for (a, b) in [("A", "B"), ("B", "C"), ("C", "A"), ("X", "Y"), ("Y", "Z"), ("Z", "X")] {
+            graph.add_edge(a.to_string(), b.to_string());
+        }
+        graph.add_edge("Lone".to_string(), "Lone".to_string());
+
+        let communities = graph.label_propagation(100, 42);
+        assert_eq!(communities["A"], 0);
+        assert_eq!(communities["B"], 0);
+        assert_eq!(communities["C"], 0);
+        assert_eq!(communities["Lone"], 1);
+        assert_eq!(communities["X"], 2);
+        assert_eq!(communities["Z"], 2);
+        assert_eq!(graph.label_propagation(100, 42), communities);
+
+        let untouched = graph.label_propagation(0, 42);
+        assert_eq!(untouched.values().collect::<std::collections::HashSet<_>>().len(), 7);
+    }
+}
@@ -0,0 +1,203 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use crate::graph::Graph;
+
+
+impl<T: Eq + Hash + Clone> Graph<T> {
+    /// Node ids of each connected component, discovered in id order.
+    pub(crate) fn component_ids(&self) -> Vec<Vec<u32>> {
+        let mut visited = vec![false; self.node_count()];
+        let mut components = Vec::new();
+
+        for start in 0..self.node_count() as u32 {
+            if visited[start as usize] {
+                continue;
+            }
+            visited[start as usize] = true;
+
+            let mut component = Vec::new();
+            let mut queue = VecDeque::new();
+            queue.push_back(start);
+            while let Some(current) = queue.pop_front() {
+                component.push(current);
+                for &neighbor in self.neighbor_ids(current) {
+                    if !visited[neighbor as usize] {
+                        visited[neighbor as usize] = true;
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+            components.push(component);
+        }
+
+        components
+    }
+
+
+    pub fn connected_components(&self) -> Vec<HashSet<T>> {
+        self.component_ids()
+            .into_iter()
+            .map(|component| component.into_iter().map(|id| self.label(id).clone()).collect())
+            .collect()
+    }
+
+
+    /// Sizes of the connected components, largest first.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        let mut sizes: Vec<usize> = self.component_ids().iter().map(Vec::len).collect();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes
+    }
+
+
+    /// Number of connected components, counted with a single visited list
+    /// and no per-component storage; 0 for the empty graph.
+    pub fn component_count(&self) -> usize {
+        let mut visited = vec![false; self.node_count()];
+        let mut stack = Vec::new();
+        let mut count = 0;
+
+        for start in 0..self.node_count() as u32 {
+            if visited[start as usize] {
+                continue;
+            }
+            count += 1;
+            visited[start as usize] = true;
+            stack.push(start);
+            while let Some(current) = stack.pop() {
+                for &neighbor in self.neighbor_ids(current) {
+                    if !visited[neighbor as usize] {
+                        visited[neighbor as usize] = true;
+                        stack.push(neighbor);
+                    }
+                }
+            }
+        }
+
+        count
+    }
+
+
+    /// True when every node is reachable from every other; the empty graph is
+    /// trivially connected.
+    pub fn is_connected(&self) -> bool {
+        self.node_count() == 0 || self.bfs_distances(0).iter().all(Option::is_some)
+    }
+}
+
+
+impl<T: Eq + Hash + Clone + Ord> Graph<T> {
+    /// Component index of every node. Components are numbered from 0 in
+    /// the sorted order of their smallest node.
+    pub fn component_labels(&self) -> HashMap<T, usize> {
+        let mut labels: Vec<Option<usize>> = vec![None; self.node_count()];
+        let mut next_label = 0;
+
+        for start in self.sorted_ids() {
+            if labels[start as usize].is_some() {
+                continue;
+            }
+            labels[start as usize] = Some(next_label);
+            let mut queue = VecDeque::new();
+            queue.push_back(start);
+            while let Some(current) = queue.pop_front() {
+                for &neighbor in self.neighbor_ids(current) {
+                    if labels[neighbor as usize].is_none() {
+                        labels[neighbor as usize] = Some(next_label);
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+            next_label += 1;
+        }
+
+        self.nodes.iter().cloned().zip(labels.into_iter().flatten()).collect()
+    }
+
+
+    /// Copy of the biggest connected component, edge weights included. Ties
+    /// go to the component holding the smallest node name.
+    pub fn largest_connected_component(&self) -> Graph<T> {
+        let smallest_label = |component: &Vec<u32>| component.iter().map(|&id| self.label(id)).min();
+        let largest = self
+            .component_ids()
+            .into_iter()
+            .max_by(|a, b| a.len().cmp(&b.len()).then_with(|| smallest_label(b).cmp(&smallest_label(a))));
+
+        match largest {
+            Some(component) => self.subgraph_of_ids(&component),
+            None => Graph::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connected_components() {
+        let mut graph = Graph::new();
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("B".to_string(), "C".to_string());
+        graph.add_edge("X".to_string(), "Y".to_string());
+
+        let components = graph.connected_components();
+        assert_eq!(components.len(), 2);
+        let abc: HashSet<String> = ["A", "B", "C"].iter().map(|s| s.to_string()).collect();
+        assert!(components.contains(&abc));
+        assert_eq!(graph.component_sizes(), vec![3, 2]);
+        assert!(Graph::<String>::new().connected_components().is_empty());
+    }
+
+    #[test]
+    fn test_component_count() {
+        let mut graph = Graph::new();
+        assert_eq!(graph.component_count(), 0);
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("B".to_string(), "C".to_string());
+        assert_eq!(graph.component_count(), 1);
+        graph.add_edge("X".to_string(), "Y".to_string());
+        graph.add_edge("Z".to_string(), "Z".to_string());
+        assert_eq!(graph.component_count(), 3);
+        assert_eq!(graph.component_count(), graph.connected_components().len());
+    }
+
+    #[test]
+    fn test_component_labels() {
+        let mut graph = Graph::new();
+        graph.add_edge("Y".to_string(), "X".to_string());
+        graph.add_edge("C".to_string(), "B".to_string());
+        graph.add_edge("B".to_string(), "Z".to_string());
+        graph.add_edge("A".to_string(), "A".to_string());
+
+        let labels = graph.component_labels();
+        assert_eq!(labels["A"], 0);
+        assert_eq!(labels["B"], 1);
+        assert_eq!(labels["C"], 1);
+        assert_eq!(labels["Z"], 1);
+        assert_eq!(labels["X"], 2);
+        assert_eq!(labels["Y"], 2);
+        assert!(Graph::<String>::new().component_labels().is_empty());
+    }
+
+    #[test]
+    fn test_is_connected_and_largest_component() {
+        let mut graph = Graph::new();
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_weighted_edge("B".to_string(), "C".to_string(), 2.0);
+        graph.add_weighted_edge("X".to_string(), "Y".to_string(), 5.0);
+        assert!(!graph.is_connected());
+
+        let largest = graph.largest_connected_component();
+        assert!(largest.is_connected());
+        assert_eq!(largest.node_count(), 3);
+        assert_eq!(largest.edge_count(), 2);
+        assert_eq!(largest.edge_weight(&"C".to_string(), &"B".to_string()), Some(2.0));
+        assert_eq!(largest.weights.len(), 1);
+
+        assert!(Graph::<String>::new().is_connected());
+        assert_eq!(Graph::<String>::new().largest_connected_component().node_count(), 0);
+    }
+}
@@ -0,0 +1,311 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+
+
+/// Directed counterpart of `Graph`: each edge runs from one node to another,
+/// and a node's degree splits into edges in and edges out.
+#[derive(Clone)]
+pub struct DiGraph<T = String> {
+    pub(crate) adjacency_list: HashMap<T, HashSet<T>>,
+}
+
+impl<T> Default for DiGraph<T> {
+    fn default() -> Self {
+        DiGraph {
+            adjacency_list: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Eq + Hash + Clone> DiGraph<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+
+    /// Records the edge `from -> to` only; both endpoints become nodes.
+    pub fn add_directed_edge(&mut self, from: T, to: T) {
+        self.adjacency_list.entry(to.clone()).or_default();
+        self.adjacency_list.entry(from).or_default().insert(to);
+    }
+
+
+    pub fn out_degree(&self, node: &T) -> usize {
+        self.adjacency_list.get(node).map_or(0, HashSet::len)
+    }
+
+
+    pub fn in_degree(&self, node: &T) -> usize {
+        self.adjacency_list.values().filter(|successors| successors.contains(node)).count()
+    }
+
+
+    /// The same nodes with every edge `from -> to` turned into `to -> from`,
+    /// so successors in the result are predecessors here.
+    pub fn reverse(&self) -> DiGraph<T> {
+        let mut reversed = DiGraph::new();
+        for (from, successors) in &self.adjacency_list {
+            reversed.adjacency_list.entry(from.clone()).or_default();
+            for to in successors {
+                reversed.add_directed_edge(to.clone(), from.clone());
+            }
+        }
+        reversed
+    }
+
+
+    /// Nodes in map order with the successors of each as indices into that
+    /// order, for the traversals that want dense bookkeeping.
+    fn indexed(&self) -> (Vec<&T>, Vec<Vec<usize>>) {
+        let nodes: Vec<&T> = self.adjacency_list.keys().collect();
+        let index: HashMap<&T, usize> = nodes.iter().enumerate().map(|(i, &node)| (node, i)).collect();
+        let successors =
+            nodes.iter().map(|&node| self.adjacency_list[node].iter().map(|to| index[to]).collect()).collect();
+        (nodes, successors)
+    }
+
+
+    /// Strongly connected components by an iterative Tarjan's algorithm:
+    /// maximal sets in which every node can reach every other along edge
+    /// directions. Every node is in exactly one component; the components
+    /// come in no particular order.
+    pub fn strongly_connected_components(&self) -> Vec<HashSet<T>> {
+        let (nodes, successors) = self.indexed();
+        let n = nodes.len();
+        let mut discovery: Vec<Option<usize>> = vec![None; n];
+        let mut low = vec![0; n];
+        let mut on_stack = vec![false; n];
+        let mut stack: Vec<usize> = Vec::new();
+        let mut components = Vec::new();
+        let mut time = 0;
+
+        for root in 0..n {
+            if discovery[root].is_some() {
+                continue;
+            }
+            let mut calls: Vec<(usize, usize)> = vec![(root, 0)];
+            discovery[root] = Some(time);
+            low[root] = time;
+            time += 1;
+            stack.push(root);
+            on_stack[root] = true;
+
+            while let Some((node, next)) = calls.last_mut() {
+                let node = *node;
+                if let Some(&successor) = successors[node].get(*next) {
+                    *next += 1;
+                    match discovery[successor] {
+                        None => {
+                            discovery[successor] = Some(time);
+                            low[successor] = time;
+                            time += 1;
+                            stack.push(successor);
+                            on_stack[successor] = true;
+                            calls.push((successor, 0));
+                        }
+                        Some(seen) if on_stack[successor] => low[node] = low[node].min(seen),
+                        Some(_) => {}
+                    }
+                    continue;
+                }
+
+                calls.pop();
+                if let Some(&(caller, _)) = calls.last() {
+                    low[caller] = low[caller].min(low[node]);
+                }
+                if Some(low[node]) == discovery[node] {
+                    let mut component = HashSet::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.insert(nodes[member].clone());
+                        if member == node {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+
+        components
+    }
+
+
+    /// Weakly connected components: the connected components once edge
+    /// directions are ignored, in no particular order.
+    pub fn weakly_connected_components(&self) -> Vec<HashSet<T>> {
+        let (nodes, successors) = self.indexed();
+        let mut neighbors = successors.clone();
+        for (from, targets) in successors.iter().enumerate() {
+            for &to in targets {
+                neighbors[to].push(from);
+            }
+        }
+
+        let mut visited = vec![false; nodes.len()];
+        let mut components = Vec::new();
+        for start in 0..nodes.len() {
+            if visited[start] {
+                continue;
+            }
+            visited[start] = true;
+            let mut component = HashSet::new();
+            let mut pending = vec![start];
+            while let Some(current) = pending.pop() {
+                component.insert(nodes[current].clone());
+                for &neighbor in &neighbors[current] {
+                    if !visited[neighbor] {
+                        visited[neighbor] = true;
+                        pending.push(neighbor);
+                    }
+                }
+            }
+            components.push(component);
+        }
+        components
+    }
+
+
+    /// In-degree of every node, indexed like `indexed`.
+    fn in_degrees(successors: &[Vec<usize>]) -> Vec<usize> {
+        let mut in_degrees = vec![0; successors.len()];
+        for &to in successors.iter().flatten() {
+            in_degrees[to] += 1;
+        }
+        in_degrees
+    }
+
+
+    /// True when the graph has no directed cycle, self-loops included. Runs
+    /// Kahn's algorithm without ordering the output.
+    pub fn is_dag(&self) -> bool {
+        let (nodes, successors) = self.indexed();
+        let mut in_degrees = Self::in_degrees(&successors);
+        let mut ready: Vec<usize> = (0..nodes.len()).filter(|&node| in_degrees[node] == 0).collect();
+        let mut removed = 0;
+        while let Some(node) = ready.pop() {
+            removed += 1;
+            for &successor in &successors[node] {
+                in_degrees[successor] -= 1;
+                if in_degrees[successor] == 0 {
+                    ready.push(successor);
+                }
+            }
+        }
+        removed == nodes.len()
+    }
+}
+
+
+impl<T: Eq + Hash + Clone + Ord> DiGraph<T> {
+    /// Nodes ordered so every edge points forward, by Kahn's algorithm. Among
+    /// the nodes ready at each step the smallest comes first, so the order is
+    /// deterministic. `None` if the graph has a cycle.
+    pub fn topological_sort(&self) -> Option<Vec<T>> {
+        let (nodes, successors) = self.indexed();
+        let mut in_degrees = Self::in_degrees(&successors);
+        let mut ready: BinaryHeap<Reverse<(&T, usize)>> = (0..nodes.len())
+            .filter(|&node| in_degrees[node] == 0)
+            .map(|node| Reverse((nodes[node], node)))
+            .collect();
+
+        let mut order = Vec::with_capacity(nodes.len());
+        while let Some(Reverse((label, node))) = ready.pop() {
+            order.push(label.clone());
+            for &successor in &successors[node] {
+                in_degrees[successor] -= 1;
+                if in_degrees[successor] == 0 {
+                    ready.push(Reverse((nodes[successor], successor)));
+                }
+            }
+        }
+        (order.len() == nodes.len()).then_some(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directed_degrees() {
+        let mut graph = DiGraph::new();
+        graph.add_directed_edge("A".to_string(), "B".to_string());
+        graph.add_directed_edge("A".to_string(), "C".to_string());
+        graph.add_directed_edge("C".to_string(), "B".to_string());
+
+        let a = "A".to_string();
+        let b = "B".to_string();
+        assert_eq!(graph.out_degree(&a), 2);
+        assert_eq!(graph.in_degree(&a), 0);
+        assert_eq!(graph.out_degree(&b), 0);
+        assert_eq!(graph.in_degree(&b), 2);
+        assert_eq!(graph.adjacency_list.len(), 3);
+        assert_eq!(graph.in_degree(&"Z".to_string()), 0);
+    }
+
+    fn set_of(nodes: &[&str]) -> HashSet<String> {
+        nodes.iter().map(|node| node.to_string()).collect()
+    }
+
+    #[test]
+    fn test_strongly_and_weakly_connected_components() {
+        // The ring A -> B -> C -> A feeds the two-cycle D <-> E; F only points in
+        // and Z is a separate self-loop.
+        let mut graph = DiGraph::new();
+        let edges = [("A", "B"), ("B", "C"), ("C", "A"), ("C", "D"), ("D", "E"), ("E", "D"), ("F", "E"), ("Z", "Z")];
+        for (from, to) in edges {
+            graph.add_directed_edge(from.to_string(), to.to_string());
+        }
+
+        let strong = graph.strongly_connected_components();
+        assert_eq!(strong.len(), 4);
+        for expected in [set_of(&["A", "B", "C"]), set_of(&["D", "E"]), set_of(&["F"]), set_of(&["Z"])] {
+            assert!(strong.contains(&expected));
+        }
+
+        let weak = graph.weakly_connected_components();
+        assert_eq!(weak.len(), 2);
+        assert!(weak.contains(&set_of(&["A", "B", "C", "D", "E", "F"])));
+        assert!(weak.contains(&set_of(&["Z"])));
+        assert!(DiGraph::<String>::new().strongly_connected_components().is_empty());
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let mut graph = DiGraph::new();
+        let edges = [("shirt", "tie"), ("tie", "jacket"), ("trousers", "shoes"), ("trousers", "belt"), ("belt", "jacket")];
+        for (from, to) in edges {
+            graph.add_directed_edge(from.to_string(), to.to_string());
+        }
+        assert!(graph.is_dag());
+        let expected = ["shirt", "tie", "trousers", "belt", "jacket", "shoes"];
+        assert_eq!(graph.topological_sort(), Some(expected.map(String::from).to_vec()));
+
+        graph.add_directed_edge("jacket".to_string(), "shirt".to_string());
+        assert!(!graph.is_dag());
+        assert_eq!(graph.topological_sort(), None);
+
+        let mut looped = DiGraph::new();
+        looped.add_directed_edge("A".to_string(), "A".to_string());
+        assert!(!looped.is_dag());
+        assert_eq!(DiGraph::<String>::new().topological_sort(), Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut graph = DiGraph::new();
+        graph.add_directed_edge("A".to_string(), "B".to_string());
+        graph.add_directed_edge("A".to_string(), "C".to_string());
+        graph.add_directed_edge("C".to_string(), "C".to_string());
+
+        let reversed = graph.reverse();
+        let a = "A".to_string();
+        assert_eq!(reversed.out_degree(&a), 0);
+        assert_eq!(reversed.in_degree(&a), 2);
+        assert_eq!(reversed.out_degree(&"B".to_string()), 1);
+        assert_eq!(reversed.out_degree(&"C".to_string()), 2);
+        assert_eq!(reversed.reverse().adjacency_list, graph.adjacency_list);
+    }
+}
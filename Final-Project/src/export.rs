@@ -0,0 +1,340 @@
+use std::fmt::Display;
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{self, BufWriter, Write};
+
+use crate::graph::{weight_key, Graph};
+
+
+/// Graphviz keywords, matched case-insensitively, that cannot be bare IDs.
+const DOT_KEYWORDS: [&str; 6] = ["node", "edge", "graph", "digraph", "subgraph", "strict"];
+
+
+/// Quotes a Graphviz identifier unless it is a plain alphanumeric ID or a number.
+fn dot_id(name: &str) -> String {
+    let is_plain = !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !DOT_KEYWORDS.iter().any(|keyword| keyword.eq_ignore_ascii_case(name));
+    let is_numeral = !name.is_empty() && name.chars().all(|c| c.is_ascii_digit());
+
+    if is_plain || is_numeral {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+
+/// Quotes a CSV field if it holds a comma, quote, line break, or edge
+/// whitespace, doubling any quotes inside.
+fn csv_field(name: &str) -> String {
+    let needs_quotes = name.contains([',', '"', '\n', '\r']) || name.trim() != name;
+    if needs_quotes {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    } else {
+        name.to_string()
+    }
+}
+
+
+/// Escapes the five XML special characters for use in attribute values.
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+
+impl<T: Eq + Hash + Clone> Graph<T> {
+    /// Builds a graph from `labels` and a square matrix in the same order.
+    /// Any nonzero entry adds an edge, so an asymmetric matrix is made
+    /// symmetric, and diagonal entries become self-loops.
+    ///
+    /// Returns `None` if the matrix is not `labels.len()` square or a label
+    /// repeats.
+    pub fn from_adjacency_matrix(labels: Vec<T>, matrix: &[Vec<u8>]) -> Option<Graph<T>> {
+        let n = labels.len();
+        if matrix.len() != n || matrix.iter().any(|row| row.len() != n) {
+            return None;
+        }
+
+        let mut graph = Graph::new();
+        for label in labels {
+            if graph.contains_node(&label) {
+                return None;
+            }
+            graph.intern(label);
+        }
+        for (i, row) in matrix.iter().enumerate() {
+            for (j, &entry) in row.iter().enumerate() {
+                if entry != 0 {
+                    graph.adjacency[i].insert(j as u32);
+                    graph.adjacency[j].insert(i as u32);
+                }
+            }
+        }
+        Some(graph)
+    }
+
+
+    /// Writes a `degree,count` header and one row per degree present, in
+    /// ascending degree order. An empty graph gets only the header.
+    pub fn write_degree_distribution_csv(&self, path: &str) -> io::Result<()> {
+        let mut rows: Vec<(usize, usize)> = self.degree_distribution().into_iter().collect();
+        rows.sort_unstable();
+
+        let mut writer = BufWriter::new(File::create(path)?);
+        writeln!(writer, "degree,count")?;
+        for (degree, count) in rows {
+            writeln!(writer, "{},{}", degree, count)?;
+        }
+        writer.flush()
+    }
+}
+
+
+impl<T: Eq + Hash + Clone + Ord> Graph<T> {
+    /// Sorted node labels and the dense 0/1 adjacency matrix in that order.
+    /// Takes O(n²) memory, so it is meant for small graphs.
+    pub fn to_adjacency_matrix(&self) -> (Vec<T>, Vec<Vec<u8>>) {
+        let order = self.sorted_ids();
+        let mut position = vec![0; self.node_count()];
+        for (i, &id) in order.iter().enumerate() {
+            position[id as usize] = i;
+        }
+
+        let mut matrix = vec![vec![0; order.len()]; order.len()];
+        for (i, &id) in order.iter().enumerate() {
+            for &neighbor in self.neighbor_ids(id) {
+                matrix[i][position[neighbor as usize]] = 1;
+            }
+        }
+        (order.into_iter().map(|id| self.label(id).clone()).collect(), matrix)
+    }
+}
+
+
+impl<T: Eq + Hash + Clone + Ord + Display> Graph<T> {
+    /// Renders the graph as a Graphviz `graph G { ... }` block, one line per
+    /// undirected edge and one per isolated node, in sorted order.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("graph G {\n");
+        for id in self.sorted_ids() {
+            let node = self.label(id);
+            let neighbors = self.sorted_neighbor_ids(id);
+            if neighbors.is_empty() {
+                dot.push_str(&format!("    {};\n", dot_id(&node.to_string())));
+            }
+            for neighbor in neighbors.into_iter().map(|neighbor| self.label(neighbor)).filter(|neighbor| *neighbor >= node) {
+                dot.push_str(&format!("    {} -- {};\n", dot_id(&node.to_string()), dot_id(&neighbor.to_string())));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+
+    /// Renders the graph as a GraphML document with one `<node>` per node and
+    /// one `<edge>` per undirected edge, in sorted order.
+    pub fn to_graphml(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+        xml.push_str("  <graph id=\"G\" edgedefault=\"undirected\">\n");
+
+        let order = self.sorted_ids();
+        for &id in &order {
+            xml.push_str(&format!("    <node id=\"{}\"/>\n", xml_escape(&self.label(id).to_string())));
+        }
+        for &id in &order {
+            let node = self.label(id);
+            for neighbor in self.sorted_neighbor_ids(id).into_iter().map(|neighbor| self.label(neighbor)) {
+                if neighbor >= node {
+                    xml.push_str(&format!(
+                        "    <edge source=\"{}\" target=\"{}\"/>\n",
+                        xml_escape(&node.to_string()),
+                        xml_escape(&neighbor.to_string())
+                    ));
+                }
+            }
+        }
+
+        xml.push_str("  </graph>\n</graphml>\n");
+        xml
+    }
+
+
+    /// Writes one `node1,node2` row per undirected edge, sorted, so that
+    /// `build_graph_from_csv` reads back the same graph. Edges with an
+    /// explicit weight get it as a third column, and names that need it are
+    /// quoted as in RFC 4180.
+    ///
+    /// Isolated nodes have no row and are lost.
+    pub fn write_edge_list_csv(&self, path: &str) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        for id in self.sorted_ids() {
+            let node = self.label(id);
+            for neighbor_id in self.sorted_neighbor_ids(id) {
+                let neighbor = self.label(neighbor_id);
+                if neighbor < node {
+                    continue;
+                }
+                let (node, neighbor) = (csv_field(&node.to_string()), csv_field(&neighbor.to_string()));
+                match self.weights.get(&weight_key(id, neighbor_id)) {
+                    Some(weight) => writeln!(writer, "{},{},{}", node, neighbor, weight)?,
+                    None => writeln!(writer, "{},{}", node, neighbor)?,
+                }
+            }
+        }
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_dot() {
+        let mut graph = Graph::new();
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("B".to_string(), "A".to_string());
+        graph.add_edge("B".to_string(), "Smith, \"Jo\"".to_string());
+        graph.add_edge("7".to_string(), "7".to_string());
+        graph.add_edge("node".to_string(), "Graph".to_string());
+        graph.add_edge("C".to_string(), "D".to_string());
+        graph.remove_edge(&"C".to_string(), &"D".to_string());
+
+        let expected = "graph G {\n    7 -- 7;\n    A -- B;\n    B -- \"Smith, \\\"Jo\\\"\";\n    C;\n    D;\n    \
+            \"Graph\" -- \"node\";\n}\n";
+        assert_eq!(graph.to_dot(), expected);
+    }
+
+    #[test]
+    fn test_to_graphml() {
+        let mut graph = Graph::new();
+        graph.add_edge("B".to_string(), "A".to_string());
+        graph.add_edge("A".to_string(), "<R&D>".to_string());
+        graph.add_edge("C".to_string(), "C".to_string());
+
+        let expected = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+            <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n  \
+            <graph id=\"G\" edgedefault=\"undirected\">\n    \
+            <node id=\"&lt;R&amp;D&gt;\"/>\n    \
+            <node id=\"A\"/>\n    \
+            <node id=\"B\"/>\n    \
+            <node id=\"C\"/>\n    \
+            <edge source=\"&lt;R&amp;D&gt;\" target=\"A\"/>\n    \
+            <edge source=\"A\" target=\"B\"/>\n    \
+            <edge source=\"C\" target=\"C\"/>\n  \
+            </graph>\n</graphml>\n";
+        assert_eq!(graph.to_graphml(), expected);
+    }
+
+    #[test]
+    fn test_write_edge_list_csv_round_trip() {
+        let mut graph = Graph::new();
+        graph.add_edge("B".to_string(), "A".to_string());
+        graph.add_weighted_edge("A".to_string(), "C".to_string(), 2.5);
+        graph.add_edge("C".to_string(), "C".to_string());
+        graph.add_edge("C".to_string(), "Smith, \"Jo\"".to_string());
+
+        let path = std::env::temp_dir().join(format!("degree_distribution_{}_edges.csv", std::process::id()));
+        let path = path.to_str().unwrap();
+        graph.write_edge_list_csv(path).unwrap();
+        let written = std::fs::read_to_string(path).unwrap();
+        let (restored, stats) = crate::build_graph_from_csv(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        assert_eq!(written, "A,B\nA,C,2.5\nC,C\nC,\"Smith, \"\"Jo\"\"\"\n");
+        assert!(stats.skipped_lines.is_empty());
+        assert!(restored == graph);
+        assert_eq!(restored.to_json(), graph.to_json());
+    }
+
+    #[test]
+    fn test_write_degree_distribution_csv() {
+        let mut graph = Graph::new();
+        for leaf in ["A", "B", "C"] {
+            graph.add_edge("Hub".to_string(), leaf.to_string());
+        }
+        graph.add_edge("X".to_string(), "Y".to_string());
+
+        let path = std::env::temp_dir().join(format!("degree_distribution_{}_degrees.csv", std::process::id()));
+        let path = path.to_str().unwrap();
+        graph.write_degree_distribution_csv(path).unwrap();
+        let written = std::fs::read_to_string(path).unwrap();
+        Graph::<String>::new().write_degree_distribution_csv(path).unwrap();
+        let empty = std::fs::read_to_string(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        assert_eq!(written, "degree,count\n1,5\n3,1\n");
+        assert_eq!(empty, "degree,count\n");
+    }
+
+    #[test]
+    fn test_adjacency_matrix_round_trip() {
+        let mut graph = Graph::new();
+        graph.add_edge("B".to_string(), "A".to_string());
+        graph.add_edge("C".to_string(), "C".to_string());
+        graph.add_edge("D".to_string(), "E".to_string());
+        graph.remove_edge(&"D".to_string(), &"E".to_string());
+
+        let (labels, matrix) = graph.to_adjacency_matrix();
+        assert_eq!(labels, vec!["A", "B", "C", "D", "E"]);
+        assert_eq!(matrix[0], vec![0, 1, 0, 0, 0]);
+        assert_eq!(matrix[2], vec![0, 0, 1, 0, 0]);
+
+        let restored = Graph::from_adjacency_matrix(labels, &matrix).unwrap();
+        assert!(restored == graph);
+    }
+
+    #[test]
+    fn test_from_adjacency_matrix_validates_shape() {
+        let labels = vec!["A".to_string(), "B".to_string()];
+        assert!(Graph::from_adjacency_matrix(labels.clone(), &[vec![0, 1]]).is_none());
+        assert!(Graph::from_adjacency_matrix(vec!["A".to_string(); 2], &[vec![0, 1], vec![1, 0]]).is_none());
+
+        let one_way = Graph::from_adjacency_matrix(labels, &[vec![0, 1], vec![0, 0]]).unwrap();
+        assert!(one_way.has_edge(&"B".to_string(), &"A".to_string()));
+    }
+
+    #[test]
+    fn test_exports_are_byte_identical_for_equal_graphs() {
+        let edges = [("Bea", "Al"), ("Al", "Cy"), ("Cy", "Dee"), ("Dee", "Bea"), ("Al", "Dee"), ("Eve", "Eve")];
+        let build = |reversed: bool| {
+            let mut graph = Graph::new();
+            let ordered: Vec<(&str, &str)> =
+                if reversed { edges.iter().rev().map(|&(a, b)| (b, a)).collect() } else { edges.to_vec() };
+            for (a, b) in ordered {
+                graph.add_weighted_edge(a.to_string(), b.to_string(), (a.len() * b.len()) as f64);
+            }
+            graph.set_node_attribute(&"Al".to_string(), "role".to_string(), "hub".to_string());
+            graph.set_node_attribute(&"Al".to_string(), "country".to_string(), "NZ".to_string());
+            graph
+        };
+
+        let path = std::env::temp_dir().join(format!("degree_distribution_{}_stable.csv", std::process::id()));
+        let path = path.to_str().unwrap();
+        let render = |graph: &Graph| {
+            graph.write_edge_list_csv(path).unwrap();
+            let csv = std::fs::read_to_string(path).unwrap();
+            (graph.to_dot(), graph.to_graphml(), graph.to_json(), csv)
+        };
+
+        let expected = render(&build(false));
+        for reversed in [false, true, false, true] {
+            assert_eq!(render(&build(reversed)), expected);
+        }
+        std::fs::remove_file(path).unwrap();
+    }
+}
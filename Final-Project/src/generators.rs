@@ -0,0 +1,175 @@
+use crate::graph::Graph;
+use crate::rng::Rng;
+
+
+impl Graph<String> {
+    /// `n` nodes labelled `"0"` to `"n-1"` and no edges.
+    fn with_numbered_nodes(n: usize) -> Graph {
+        let mut graph = Graph::new();
+        for i in 0..n {
+            graph.intern(i.to_string());
+        }
+        graph
+    }
+
+
+    /// Erdős-Rényi G(n, p): `n` nodes labelled `"0"` to `"n-1"`, each pair
+    /// joined independently with probability `p`. `p <= 0` gives no edges
+    /// and `p >= 1` the complete graph. The same seed always gives the same
+    /// graph.
+    pub fn erdos_renyi(n: usize, p: f64, seed: u64) -> Graph {
+        let mut graph = Graph::with_numbered_nodes(n);
+        let mut rng = Rng::new(seed);
+        for i in 0..n as u32 {
+            for j in i + 1..n as u32 {
+                if rng.next_f64() < p {
+                    graph.adjacency[i as usize].insert(j);
+                    graph.adjacency[j as usize].insert(i);
+                }
+            }
+        }
+        graph
+    }
+
+
+    /// Barabási-Albert preferential attachment: starts from a complete graph
+    /// on `m + 1` nodes, then each further node links to `m` distinct
+    /// existing nodes chosen with probability proportional to their degree,
+    /// for `n` nodes labelled `"0"` to `"n-1"` in total. With `n <= m + 1`
+    /// this is just the complete graph on `n` nodes, and `m = 0` gives `n`
+    /// isolated nodes. The same seed always gives the same graph.
+    pub fn barabasi_albert(n: usize, m: usize, seed: u64) -> Graph {
+        let mut graph = Graph::with_numbered_nodes(n);
+        if m == 0 {
+            return graph;
+        }
+
+        // Every edge puts both endpoints here, so a uniform pick from it is
+        // a degree-proportional pick of a node.
+        let mut endpoints: Vec<u32> = Vec::new();
+        let seed_size = n.min(m + 1) as u32;
+        for i in 0..seed_size {
+            for j in i + 1..seed_size {
+                graph.adjacency[i as usize].insert(j);
+                graph.adjacency[j as usize].insert(i);
+                endpoints.extend([i, j]);
+            }
+        }
+
+        let mut rng = Rng::new(seed);
+        for new in seed_size..n as u32 {
+            let mut targets: Vec<u32> = Vec::with_capacity(m);
+            while targets.len() < m {
+                let target = endpoints[rng.below(endpoints.len())];
+                if !targets.contains(&target) {
+                    targets.push(target);
+                }
+            }
+            for target in targets {
+                graph.adjacency[new as usize].insert(target);
+                graph.adjacency[target as usize].insert(new);
+                endpoints.extend([new, target]);
+            }
+        }
+        graph
+    }
+
+
+    /// Watts-Strogatz small world: a ring of `n` nodes labelled `"0"` to
+    /// `"n-1"`, each joined to its `k / 2` nearest neighbors on either side,
+    /// after which every lattice edge has its far end moved with probability
+    /// `beta` to a uniformly chosen node that is not already a neighbor. An
+    /// odd `k` is rounded down, and `k` is capped at `n - 1`. The same seed
+    /// always gives the same graph.
+    pub fn watts_strogatz(n: usize, k: usize, beta: f64, seed: u64) -> Graph {
+        let mut graph = Graph::with_numbered_nodes(n);
+        let half = k.min(n.saturating_sub(1)) / 2;
+        for offset in 1..=half {
+            for i in 0..n {
+                let j = ((i + offset) % n) as u32;
+                graph.adjacency[i].insert(j);
+                graph.adjacency[j as usize].insert(i as u32);
+            }
+        }
+
+        let mut rng = Rng::new(seed);
+        for offset in 1..=half {
+            for i in 0..n as u32 {
+                let j = (i + offset as u32) % n as u32;
+                if rng.next_f64() >= beta || graph.adjacency[i as usize].len() >= n - 1 {
+                    continue;
+                }
+                let target = loop {
+                    let candidate = rng.below(n) as u32;
+                    if candidate != i && !graph.adjacency[i as usize].contains(&candidate) {
+                        break candidate;
+                    }
+                };
+                if graph.adjacency[i as usize].remove(&j) {
+                    graph.adjacency[j as usize].remove(&i);
+                    graph.adjacency[i as usize].insert(target);
+                    graph.adjacency[target as usize].insert(i);
+                }
+            }
+        }
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erdos_renyi() {
+        assert_eq!(Graph::erdos_renyi(10, 0.0, 1).edge_count(), 0);
+        assert_eq!(Graph::erdos_renyi(10, 0.0, 1).node_count(), 10);
+        assert_eq!(Graph::erdos_renyi(10, 1.0, 1).edge_count(), 45);
+
+        let graph = Graph::erdos_renyi(200, 0.05, 7);
+        assert!(graph == Graph::erdos_renyi(200, 0.05, 7));
+        assert!(graph != Graph::erdos_renyi(200, 0.05, 8));
+        // Expect 0.05 * 19900 = 995 edges, give or take a few standard deviations.
+        assert!((900..1100).contains(&graph.edge_count()));
+        assert_eq!(graph.self_loop_count(), 0);
+    }
+
+    #[test]
+    fn test_barabasi_albert() {
+        let graph = Graph::barabasi_albert(2000, 2, 11);
+        assert_eq!(graph.node_count(), 2000);
+        // The seed triangle plus two edges for each of the other 1997 nodes.
+        assert_eq!(graph.edge_count(), 3 + 2 * 1997);
+        assert!(graph == Graph::barabasi_albert(2000, 2, 11));
+
+        let distribution = graph.degree_distribution();
+        let (alpha, fit) = crate::evaluate_fitted_power_law(&distribution).unwrap();
+        assert!(fit > 0.95);
+        assert!((2.0..4.0).contains(&alpha));
+        // An Erdős-Rényi graph of the same mean degree fits far worse.
+        let random = Graph::erdos_renyi(2000, 4.0 / 1999.0, 11).degree_distribution();
+        assert!(crate::evaluate_fitted_power_law(&random).unwrap().1 < fit - 0.1);
+        assert!(graph.degree_stats().unwrap().max > 40);
+
+        assert_eq!(Graph::barabasi_albert(3, 5, 1).edge_count(), 3);
+        assert_eq!(Graph::barabasi_albert(4, 0, 1).edge_count(), 0);
+    }
+
+    #[test]
+    fn test_watts_strogatz() {
+        let lattice = Graph::watts_strogatz(100, 4, 0.0, 3);
+        assert_eq!(lattice.edge_count(), 200);
+        assert!(lattice.nodes().all(|node| lattice.degree(node) == 4));
+        // A ring lattice with k = 4 has clustering 3(k - 2) / 4(k - 1) = 0.5.
+        assert!((lattice.average_clustering_coefficient() - 0.5).abs() < 1e-12);
+
+        let small_world = Graph::watts_strogatz(100, 4, 0.1, 3);
+        assert_eq!(small_world.edge_count(), 200);
+        assert!(small_world == Graph::watts_strogatz(100, 4, 0.1, 3));
+        assert!(small_world.average_clustering_coefficient() > 0.25);
+        let lattice_length = lattice.average_path_length().unwrap();
+        assert!(small_world.average_path_length().is_some_and(|length| length < 0.6 * lattice_length));
+
+        assert!(Graph::watts_strogatz(5, 10, 0.5, 1) == Graph::erdos_renyi(5, 1.0, 1));
+    }
+}
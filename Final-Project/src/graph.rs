@@ -0,0 +1,1302 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::sync::OnceLock;
+
+
+/// Undirected graph over nodes of any hashable type, `String` by default.
+///
+/// Each distinct node is interned to a dense `u32` id on insertion, so the
+/// neighbor sets and traversal bookkeeping work on small integers rather than
+/// on owned copies of the labels.
+///
+/// Neighbor sets are unordered, so `neighbors` and the traversal internals
+/// follow no fixed order. Anything meant to be compared or stored instead
+/// goes through sorted labels: `sorted_nodes`, `sorted_neighbors`, `edges`,
+/// and every exporter (`to_dot`, `to_graphml`, `to_json`,
+/// `write_edge_list_csv`), which give byte-identical output for equal
+/// graphs however they were built.
+#[derive(Clone)]
+pub struct Graph<T = String> {
+    /// Node labels, indexed by id.
+    pub(crate) nodes: Vec<T>,
+    pub(crate) ids: HashMap<T, u32>,
+    /// Neighbor ids of each node, indexed by id.
+    pub(crate) adjacency: Vec<HashSet<u32>>,
+    /// Weights of edges added through `add_weighted_edge`, keyed by `weight_key`.
+    pub(crate) weights: HashMap<(u32, u32), f64>,
+    /// Whether repeated edges are counted instead of collapsed.
+    pub(crate) multigraph: bool,
+    /// In multigraph mode, copies of each edge beyond the first, keyed by
+    /// `weight_key`; edges added once have no entry.
+    pub(crate) multiplicities: HashMap<(u32, u32), usize>,
+    /// Key-value metadata per node id; nodes without any have no entry.
+    pub(crate) attributes: HashMap<u32, HashMap<String, String>>,
+    /// `degree_distribution`, filled on first use. Anything that changes
+    /// `adjacency` must reset it through `invalidate_degree_cache`; `intern`,
+    /// `link` and the removal methods do.
+    pub(crate) degree_cache: OnceLock<HashMap<usize, usize>>,
+}
+
+impl<T> Default for Graph<T> {
+    fn default() -> Self {
+        Graph {
+            nodes: Vec::new(),
+            ids: HashMap::new(),
+            adjacency: Vec::new(),
+            weights: HashMap::new(),
+            multigraph: false,
+            multiplicities: HashMap::new(),
+            attributes: HashMap::new(),
+            degree_cache: OnceLock::new(),
+        }
+    }
+}
+
+/// Two graphs are equal when they have the same node labels and each node has
+/// the same neighbor labels, whatever the ids or insertion order. Weights,
+/// multiplicities and attributes are not compared.
+impl<T: Eq + Hash + Clone> PartialEq for Graph<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node_count() == other.node_count()
+            && self.nodes.iter().zip(&self.adjacency).all(|(node, neighbors)| match other.id(node) {
+                Some(other_id) => {
+                    let other_neighbors = other.neighbor_ids(other_id);
+                    neighbors.len() == other_neighbors.len()
+                        && neighbors
+                            .iter()
+                            .all(|&neighbor| other.id(self.label(neighbor)).is_some_and(|id| other_neighbors.contains(&id)))
+                }
+                None => false,
+            })
+    }
+}
+
+impl<T: Eq + Hash + Clone> Eq for Graph<T> {}
+
+/// One-shot summary of the node degrees, from `Graph::degree_stats`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DegreeStats {
+    pub min: usize,
+    pub max: usize,
+    pub mean: f64,
+    /// Average of the two middle degrees when the node count is even.
+    pub median: f64,
+    /// Population standard deviation.
+    pub std_dev: f64,
+}
+
+/// Order-independent key for the undirected edge between two ids.
+pub(crate) fn weight_key(id1: u32, id2: u32) -> (u32, u32) {
+    (id1.min(id2), id1.max(id2))
+}
+
+/// Moves the entry for edge `from` (if any) to edge `to`.
+fn rekey<V>(map: &mut HashMap<(u32, u32), V>, from: (u32, u32), to: (u32, u32)) {
+    if let Some(value) = map.remove(&from) {
+        map.insert(to, value);
+    }
+}
+
+impl<T: Eq + Hash + Clone> Graph<T> {
+    pub fn new() -> Self {
+        Graph::default()
+    }
+
+
+    /// An empty graph that keeps parallel edges: adding an edge that already
+    /// exists raises its `edge_multiplicity` instead of being a no-op.
+    /// Everything else (neighbors, `degree`, `edge_count`) still sees each
+    /// pair of nodes once.
+    pub fn new_multigraph() -> Self {
+        Graph {
+            multigraph: true,
+            ..Graph::default()
+        }
+    }
+
+
+    pub fn is_multigraph(&self) -> bool {
+        self.multigraph
+    }
+
+
+    /// Builds a graph by calling `add_edge` on each pair in turn.
+    pub fn from_edges<I: IntoIterator<Item = (T, T)>>(edges: I) -> Self {
+        let mut graph = Graph::new();
+        for (node1, node2) in edges {
+            graph.add_edge(node1, node2);
+        }
+        graph
+    }
+
+
+    pub(crate) fn id(&self, node: &T) -> Option<u32> {
+        self.ids.get(node).copied()
+    }
+
+
+    /// Id of `node`, adding it as an isolated node first if it is new.
+    pub(crate) fn intern(&mut self, node: T) -> u32 {
+        if let Some(id) = self.id(&node) {
+            return id;
+        }
+        self.invalidate_degree_cache();
+        let id = self.nodes.len() as u32;
+        self.ids.insert(node.clone(), id);
+        self.nodes.push(node);
+        self.adjacency.push(HashSet::new());
+        id
+    }
+
+
+    /// Like `intern`, but clones `node` only when it is new.
+    fn intern_ref(&mut self, node: &T) -> u32 {
+        match self.id(node) {
+            Some(id) => id,
+            None => self.intern(node.clone()),
+        }
+    }
+
+
+    pub(crate) fn invalidate_degree_cache(&mut self) {
+        self.degree_cache.take();
+    }
+
+
+    /// Inserts the edge between two ids, counting the repeat in multigraph mode.
+    fn link(&mut self, id1: u32, id2: u32) {
+        self.invalidate_degree_cache();
+        let is_new = self.adjacency[id1 as usize].insert(id2);
+        self.adjacency[id2 as usize].insert(id1);
+        if !is_new && self.multigraph {
+            *self.multiplicities.entry(weight_key(id1, id2)).or_insert(0) += 1;
+        }
+    }
+
+
+    pub(crate) fn neighbor_ids(&self, id: u32) -> &HashSet<u32> {
+        &self.adjacency[id as usize]
+    }
+
+
+    pub(crate) fn label(&self, id: u32) -> &T {
+        &self.nodes[id as usize]
+    }
+
+
+    /// Subgraph induced by `ids`: those nodes with their attributes, the edges
+    /// among them, and their weights. Nodes keep their relative id order.
+    pub(crate) fn subgraph_of_ids(&self, ids: &[u32]) -> Graph<T> {
+        let mut keep: Vec<u32> = ids.to_vec();
+        keep.sort_unstable();
+        keep.dedup();
+        let mut new_ids = vec![None; self.node_count()];
+        let mut subgraph = Graph {
+            multigraph: self.multigraph,
+            ..Graph::new()
+        };
+        for &id in &keep {
+            let new_id = subgraph.intern(self.label(id).clone());
+            new_ids[id as usize] = Some(new_id);
+            if let Some(attributes) = self.attributes.get(&id) {
+                subgraph.attributes.insert(new_id, attributes.clone());
+            }
+        }
+
+        for &id in &keep {
+            let new_id = new_ids[id as usize].unwrap();
+            for &neighbor in self.neighbor_ids(id) {
+                if let Some(new_neighbor) = new_ids[neighbor as usize] {
+                    subgraph.adjacency[new_id as usize].insert(new_neighbor);
+                    if let Some(&weight) = self.weights.get(&weight_key(id, neighbor)) {
+                        subgraph.weights.insert(weight_key(new_id, new_neighbor), weight);
+                    }
+                    if let Some(&extra) = self.multiplicities.get(&weight_key(id, neighbor)) {
+                        subgraph.multiplicities.insert(weight_key(new_id, new_neighbor), extra);
+                    }
+                }
+            }
+        }
+        subgraph
+    }
+
+
+    /// The nodes of `nodes` that are in the graph, the edges between them, and
+    /// their weights and attributes. Names not in the graph are ignored.
+    pub fn induced_subgraph(&self, nodes: &HashSet<T>) -> Graph<T> {
+        let ids: Vec<u32> = nodes.iter().filter_map(|node| self.id(node)).collect();
+        self.subgraph_of_ids(&ids)
+    }
+
+
+    pub fn add_edge(&mut self, node1: T, node2: T) {
+        let id1 = self.intern(node1);
+        let id2 = self.intern(node2);
+        self.link(id1, id2);
+    }
+
+
+    /// Same result as calling `add_edge` for every pair, but reserves room up
+    /// front and clones a name only the first time it is seen.
+    pub fn add_edges(&mut self, edges: &[(T, T)]) {
+        self.nodes.reserve(edges.len());
+        self.ids.reserve(edges.len());
+        self.adjacency.reserve(edges.len());
+        for (node1, node2) in edges {
+            let id1 = self.intern_ref(node1);
+            let id2 = self.intern_ref(node2);
+            self.link(id1, id2);
+        }
+    }
+
+
+    /// Adds the edge and adds `weight` to the weights explicitly given to it
+    /// so far. `add_edge` never gives an edge a weight, so an edge added with
+    /// it first is not counted.
+    pub fn add_weighted_edge(&mut self, node1: T, node2: T, weight: f64) {
+        let id1 = self.intern(node1);
+        let id2 = self.intern(node2);
+        self.link(id1, id2);
+        *self.weights.entry(weight_key(id1, id2)).or_insert(0.0) += weight;
+    }
+
+
+    /// Adds one loaded row of weight `weight`, so that an edge weighs the sum
+    /// of its rows. A total of 1.0 is left implicit rather than stored.
+    pub(crate) fn add_edge_row(&mut self, node1: T, node2: T, weight: f64) {
+        let id1 = self.intern(node1);
+        let id2 = self.intern(node2);
+        let total = if self.neighbor_ids(id1).contains(&id2) { self.weight_of(id1, id2) + weight } else { weight };
+        self.link(id1, id2);
+        if total == 1.0 {
+            self.weights.remove(&weight_key(id1, id2));
+        } else {
+            self.weights.insert(weight_key(id1, id2), total);
+        }
+    }
+
+
+    /// Weight of the edge between `node1` and `node2`, or `None` if there is no
+    /// such edge. Edges added without an explicit weight weigh 1.0.
+    pub fn edge_weight(&self, node1: &T, node2: &T) -> Option<f64> {
+        let (id1, id2) = (self.id(node1)?, self.id(node2)?);
+        if !self.neighbor_ids(id1).contains(&id2) {
+            return None;
+        }
+        Some(self.weight_of(id1, id2))
+    }
+
+
+    /// Weight between two adjacent ids, 1.0 unless set explicitly.
+    pub(crate) fn weight_of(&self, id1: u32, id2: u32) -> f64 {
+        self.weights.get(&weight_key(id1, id2)).copied().unwrap_or(1.0)
+    }
+
+
+    /// Number of times the edge was added: 0 without an edge, and always 1
+    /// outside multigraph mode.
+    pub fn edge_multiplicity(&self, node1: &T, node2: &T) -> usize {
+        match (self.id(node1), self.id(node2)) {
+            (Some(id1), Some(id2)) if self.neighbor_ids(id1).contains(&id2) => {
+                1 + self.multiplicities.get(&weight_key(id1, id2)).copied().unwrap_or(0)
+            }
+            _ => 0,
+        }
+    }
+
+
+    /// Edges beyond the first between any pair of nodes; 0 outside
+    /// multigraph mode.
+    pub fn parallel_edge_count(&self) -> usize {
+        self.multiplicities.values().sum()
+    }
+
+
+    /// Deletes the edge between `node1` and `node2`, every parallel copy
+    /// included; both nodes stay in the graph.
+    pub fn remove_edge(&mut self, node1: &T, node2: &T) {
+        let (Some(id1), Some(id2)) = (self.id(node1), self.id(node2)) else {
+            return;
+        };
+        self.invalidate_degree_cache();
+        self.adjacency[id1 as usize].remove(&id2);
+        self.adjacency[id2 as usize].remove(&id1);
+        self.weights.remove(&weight_key(id1, id2));
+        self.multiplicities.remove(&weight_key(id1, id2));
+    }
+
+
+    /// Removes `node` and its edges. The last node takes over the freed id so
+    /// ids stay dense.
+    pub fn remove_node(&mut self, node: &T) {
+        let Some(id) = self.ids.remove(node) else {
+            return;
+        };
+        self.invalidate_degree_cache();
+        self.attributes.remove(&id);
+        for neighbor in std::mem::take(&mut self.adjacency[id as usize]) {
+            self.adjacency[neighbor as usize].remove(&id);
+            self.weights.remove(&weight_key(id, neighbor));
+            self.multiplicities.remove(&weight_key(id, neighbor));
+        }
+
+        let last = (self.nodes.len() - 1) as u32;
+        self.nodes.swap_remove(id as usize);
+        self.adjacency.swap_remove(id as usize);
+        if id == last {
+            return;
+        }
+        *self.ids.get_mut(&self.nodes[id as usize]).unwrap() = id;
+        if let Some(attributes) = self.attributes.remove(&last) {
+            self.attributes.insert(id, attributes);
+        }
+        let moved_neighbors: Vec<u32> = self.adjacency[id as usize].iter().copied().collect();
+        for neighbor in moved_neighbors {
+            let neighbor = if neighbor == last { id } else { neighbor };
+            let neighbor_set = &mut self.adjacency[neighbor as usize];
+            neighbor_set.remove(&last);
+            neighbor_set.insert(id);
+            let old_key = weight_key(last, if neighbor == id { last } else { neighbor });
+            rekey(&mut self.weights, old_key, weight_key(id, neighbor));
+            rekey(&mut self.multiplicities, old_key, weight_key(id, neighbor));
+        }
+    }
+
+
+    /// Drops every edge from a node to itself, keeping the node.
+    ///
+    /// A self-loop counts 1 towards its node's `degree` and as one edge in
+    /// `edge_count`, so both shrink by one per removed loop.
+    pub fn remove_self_loops(&mut self) {
+        self.invalidate_degree_cache();
+        for (id, neighbors) in self.adjacency.iter_mut().enumerate() {
+            let id = id as u32;
+            if neighbors.remove(&id) {
+                self.weights.remove(&(id, id));
+                self.multiplicities.remove(&(id, id));
+            }
+        }
+    }
+
+
+    /// Adds every node and edge of `other` to this graph. Explicit weights
+    /// accumulate, exactly as if the edges of `other` had been added here
+    /// with the same calls: shared edges are kept once, or in multigraph mode their
+    /// multiplicities add up. Node attributes from `other` overwrite existing
+    /// values for the same key.
+    pub fn merge(&mut self, other: &Graph<T>) {
+        let new_ids: Vec<u32> = other.nodes.iter().map(|node| self.intern(node.clone())).collect();
+        for (&id, attributes) in &other.attributes {
+            self.attributes.entry(new_ids[id as usize]).or_default().extend(attributes.clone());
+        }
+        for (id, neighbors) in other.adjacency.iter().enumerate() {
+            for &neighbor in neighbors.iter().filter(|&&neighbor| neighbor as usize >= id) {
+                let (new_id, new_neighbor) = (new_ids[id], new_ids[neighbor as usize]);
+                let copies = 1 + other.multiplicities.get(&weight_key(id as u32, neighbor)).copied().unwrap_or(0);
+                for _ in 0..copies {
+                    self.link(new_id, new_neighbor);
+                }
+            }
+        }
+        for (&(id1, id2), &weight) in &other.weights {
+            let key = weight_key(new_ids[id1 as usize], new_ids[id2 as usize]);
+            *self.weights.entry(key).or_insert(0.0) += weight;
+        }
+    }
+
+
+    /// Consuming form of `merge`.
+    pub fn union(mut first: Graph<T>, second: Graph<T>) -> Graph<T> {
+        first.merge(&second);
+        first
+    }
+
+
+    /// Graph on the same nodes whose edges are exactly the missing ones, self-loops
+    /// excluded. Attributes are kept; weights and multiplicities are not. Takes
+    /// O(n²) time, and for a sparse input nearly n²/2 edges of memory.
+    pub fn complement(&self) -> Graph<T> {
+        let n = self.node_count() as u32;
+        let adjacency = (0..n)
+            .map(|id| (0..n).filter(|&other| other != id && !self.neighbor_ids(id).contains(&other)).collect())
+            .collect();
+        Graph {
+            nodes: self.nodes.clone(),
+            ids: self.ids.clone(),
+            adjacency,
+            attributes: self.attributes.clone(),
+            ..Graph::default()
+        }
+    }
+
+
+    /// Edges have no direction, so reversing them changes nothing: this is a
+    /// plain clone, kept so code written for `DiGraph::reverse` also works here.
+    pub fn reverse(&self) -> Graph<T> {
+        self.clone()
+    }
+
+
+    /// Every node, in insertion order (`remove_node` moves the last node into
+    /// the removed one's place).
+    pub fn nodes(&self) -> impl Iterator<Item = &T> {
+        self.nodes.iter()
+    }
+
+
+    /// Neighbors of `node` in no particular order; empty for an unknown node.
+    pub fn neighbors<'a>(&'a self, node: &T) -> impl Iterator<Item = &'a T> + 'a {
+        let neighbors = self.id(node).map(|id| self.neighbor_ids(id));
+        neighbors.into_iter().flatten().map(|&neighbor| self.label(neighbor))
+    }
+
+
+    /// Sets `key` to `value` on `node`, adding the node (with no edges) if
+    /// it is new.
+    pub fn set_node_attribute(&mut self, node: &T, key: String, value: String) {
+        let id = self.intern_ref(node);
+        self.attributes.entry(id).or_default().insert(key, value);
+    }
+
+
+    pub fn get_node_attribute(&self, node: &T, key: &str) -> Option<&String> {
+        self.attributes.get(&self.id(node)?)?.get(key)
+    }
+
+
+    pub fn has_edge(&self, node1: &T, node2: &T) -> bool {
+        match (self.id(node1), self.id(node2)) {
+            (Some(id1), Some(id2)) => self.neighbor_ids(id1).contains(&id2),
+            _ => false,
+        }
+    }
+
+
+    pub fn contains_node(&self, node: &T) -> bool {
+        self.ids.contains_key(node)
+    }
+
+
+    /// Size of `node`'s neighbor set, the same count `degree_distribution` uses;
+    /// 0 for an unknown node.
+    pub fn degree(&self, node: &T) -> usize {
+        self.id(node).map_or(0, |id| self.neighbor_ids(id).len())
+    }
+
+
+    /// `degree` with every parallel copy of an edge counted; the same as
+    /// `degree` outside multigraph mode.
+    pub fn degree_with_multiplicity(&self, node: &T) -> usize {
+        let Some(id) = self.id(node) else {
+            return 0;
+        };
+        let extra: usize = self
+            .neighbor_ids(id)
+            .iter()
+            .filter_map(|&neighbor| self.multiplicities.get(&weight_key(id, neighbor)))
+            .sum();
+        self.neighbor_ids(id).len() + extra
+    }
+
+
+    /// Number of nodes of each degree. Computed once and cached until the
+    /// graph next changes, so repeated calls only copy the stored map.
+    pub fn degree_distribution(&self) -> HashMap<usize, usize> {
+        self.degree_cache
+            .get_or_init(|| {
+                let mut distribution = HashMap::new();
+
+                for neighbors in &self.adjacency {
+                    let degree = neighbors.len();
+                    *distribution.entry(degree).or_insert(0) += 1;
+                }
+
+                distribution
+            })
+            .clone()
+    }
+
+
+    /// `degree_distribution` as fractions of `node_count`, i.e. P(k). Empty for
+    /// an empty graph.
+    pub fn degree_distribution_normalized(&self) -> HashMap<usize, f64> {
+        let n = self.node_count() as f64;
+        self.degree_distribution().into_iter().map(|(degree, count)| (degree, count as f64 / n)).collect()
+    }
+
+
+    /// `(ln k, ln P(k))` for every degree `k > 0` present, in increasing
+    /// degree order, ready for a log-log plot. Degree 0 is skipped since its
+    /// logarithm is undefined, but those nodes still count towards P(k).
+    pub fn degree_loglog_points(&self) -> Vec<(f64, f64)> {
+        let n = self.node_count() as f64;
+        let mut counts: Vec<(usize, usize)> =
+            self.degree_distribution().into_iter().filter(|&(degree, _)| degree > 0).collect();
+        counts.sort_unstable();
+        counts.into_iter().map(|(degree, count)| ((degree as f64).ln(), (count as f64 / n).ln())).collect()
+    }
+
+
+    /// Minimum, maximum, mean, median and standard deviation of the node
+    /// degrees; `None` for an empty graph.
+    pub fn degree_stats(&self) -> Option<DegreeStats> {
+        let mut degrees: Vec<usize> = self.adjacency.iter().map(HashSet::len).collect();
+        degrees.sort_unstable();
+        let (&min, &max) = (degrees.first()?, degrees.last()?);
+
+        let n = degrees.len() as f64;
+        let mean = degrees.iter().sum::<usize>() as f64 / n;
+        let middle = degrees.len() / 2;
+        let median = if degrees.len() % 2 == 1 {
+            degrees[middle] as f64
+        } else {
+            (degrees[middle - 1] + degrees[middle]) as f64 / 2.0
+        };
+        let variance = degrees.iter().map(|&degree| (degree as f64 - mean).powi(2)).sum::<f64>() / n;
+
+        Some(DegreeStats { min, max, mean, median, std_dev: variance.sqrt() })
+    }
+
+
+    /// Complementary cumulative distribution P(K >= k) for every degree `k`
+    /// present in the graph, in increasing degree order.
+    pub fn degree_ccdf(&self) -> Vec<(usize, f64)> {
+        let mut counts: Vec<(usize, usize)> = self.degree_distribution().into_iter().collect();
+        counts.sort_unstable();
+
+        let n = self.node_count() as f64;
+        let mut at_least = self.node_count();
+        let mut ccdf = Vec::with_capacity(counts.len());
+        for (degree, count) in counts {
+            ccdf.push((degree, at_least as f64 / n));
+            at_least -= count;
+        }
+        ccdf
+    }
+
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+
+    /// Number of unique undirected edges. A self-loop appears once in its
+    /// node's neighbor set and is counted as a single edge.
+    pub fn edge_count(&self) -> usize {
+        let mut self_loops = 0;
+        let mut degree_sum = 0;
+        for (id, neighbors) in self.adjacency.iter().enumerate() {
+            if neighbors.contains(&(id as u32)) {
+                self_loops += 1;
+                degree_sum += neighbors.len() - 1;
+            } else {
+                degree_sum += neighbors.len();
+            }
+        }
+        degree_sum / 2 + self_loops
+    }
+
+
+    /// Number of nodes with an edge to themselves.
+    pub fn self_loop_count(&self) -> usize {
+        self.adjacency.iter().enumerate().filter(|(id, neighbors)| neighbors.contains(&(*id as u32))).count()
+    }
+
+
+    /// `2 * edges / (n * (n - 1))`, or 0.0 with fewer than two nodes.
+    pub fn density(&self) -> f64 {
+        let n = self.node_count();
+        if n < 2 {
+            return 0.0;
+        }
+        2.0 * self.edge_count() as f64 / (n * (n - 1)) as f64
+    }
+
+
+    /// Number of nodes exactly two hops from `node`; the size of
+    /// `neighbors_at_distance_two_set`.
+    pub fn neighbors_at_distance_two(&self, node: &T) -> usize {
+        self.neighbors_at_distance_two_set(node).len()
+    }
+
+
+    /// Distinct nodes that are neighbors of a neighbor of `node` but neither
+    /// `node` itself nor one of its direct neighbors; empty for an unknown
+    /// node.
+    pub fn neighbors_at_distance_two_set(&self, node: &T) -> HashSet<T> {
+        let Some(id) = self.id(node) else {
+            return HashSet::new();
+        };
+        let direct = self.neighbor_ids(id);
+
+        let mut distance_two_neighbors = HashSet::new();
+        for &neighbor in direct {
+            for &second_neighbor in self.neighbor_ids(neighbor) {
+                if second_neighbor != id && !direct.contains(&second_neighbor) {
+                    distance_two_neighbors.insert(second_neighbor);
+                }
+            }
+        }
+
+        distance_two_neighbors.into_iter().map(|second_neighbor| self.label(second_neighbor).clone()).collect()
+    }
+
+
+    /// Counts the nodes whose shortest-path distance from `node` is exactly `k`.
+    ///
+    /// The source itself is never counted, so `k == 0` always yields 0.
+    pub fn neighbors_at_distance_k(&self, node: &T, k: usize) -> usize {
+        let Some(source) = self.id(node) else {
+            return 0;
+        };
+        if k == 0 {
+            return 0;
+        }
+
+        let mut visited = vec![false; self.node_count()];
+        visited[source as usize] = true;
+        let mut queue = VecDeque::new();
+        queue.push_back((source, 0));
+        let mut count = 0;
+
+        while let Some((current, level)) = queue.pop_front() {
+            if level == k {
+                count += 1;
+                continue;
+            }
+            for &neighbor in self.neighbor_ids(current) {
+                if !visited[neighbor as usize] {
+                    visited[neighbor as usize] = true;
+                    queue.push_back((neighbor, level + 1));
+                }
+            }
+        }
+
+        count
+    }
+}
+
+impl<T: Eq + Hash + Clone + Ord> Graph<T> {
+    /// All node ids, ordered by label.
+    pub(crate) fn sorted_ids(&self) -> Vec<u32> {
+        let mut ids: Vec<u32> = (0..self.nodes.len() as u32).collect();
+        ids.sort_by(|a, b| self.label(*a).cmp(self.label(*b)));
+        ids
+    }
+
+
+    pub fn sorted_nodes(&self) -> Vec<&T> {
+        let mut nodes: Vec<&T> = self.nodes.iter().collect();
+        nodes.sort();
+        nodes
+    }
+
+
+    /// Each undirected edge once as `(smaller, larger)`, in sorted order.
+    /// A self-loop appears as `(node, node)`.
+    pub fn edges(&self) -> impl Iterator<Item = (T, T)> {
+        let mut edges: Vec<(T, T)> = Vec::with_capacity(self.edge_count());
+        for (id, neighbors) in self.adjacency.iter().enumerate() {
+            let node = &self.nodes[id];
+            for &neighbor in neighbors {
+                let neighbor = self.label(neighbor);
+                if neighbor >= node {
+                    edges.push((node.clone(), neighbor.clone()));
+                }
+            }
+        }
+        edges.sort();
+        edges.into_iter()
+    }
+
+
+    /// Nodes with no neighbors at all, in sorted order.
+    pub fn isolated_nodes(&self) -> Vec<T> {
+        self.nodes_with_degree_below(1)
+    }
+
+
+    /// Nodes whose `degree` is less than `k`, in sorted order.
+    pub fn nodes_with_degree_below(&self, k: usize) -> Vec<T> {
+        let mut nodes: Vec<T> = self
+            .nodes
+            .iter()
+            .zip(&self.adjacency)
+            .filter(|(_, neighbors)| neighbors.len() < k)
+            .map(|(node, _)| node.clone())
+            .collect();
+        nodes.sort();
+        nodes
+    }
+
+
+    /// Node ids by descending degree, ties in sorted order.
+    fn ids_by_degree(&self) -> Vec<u32> {
+        let mut ids = self.sorted_ids();
+        ids.sort_by_key(|&id| Reverse(self.adjacency[id as usize].len()));
+        ids
+    }
+
+
+    /// Nodes whose `degree` is at least `threshold`, highest degree first and
+    /// ties in sorted order.
+    pub fn hubs(&self, threshold: usize) -> Vec<T> {
+        self.ids_by_degree()
+            .into_iter()
+            .take_while(|&id| self.adjacency[id as usize].len() >= threshold)
+            .map(|id| self.label(id).clone())
+            .collect()
+    }
+
+
+    /// The `k` nodes of highest degree, ordered as in `hubs`; every node when
+    /// `k` exceeds the node count.
+    pub fn top_k_by_degree(&self, k: usize) -> Vec<T> {
+        self.ids_by_degree().into_iter().take(k).map(|id| self.label(id).clone()).collect()
+    }
+
+
+    /// Neighbors of `node` in sorted order; empty for an unknown node.
+    pub fn sorted_neighbors(&self, node: &T) -> Vec<&T> {
+        let mut neighbors: Vec<&T> = self.neighbors(node).collect();
+        neighbors.sort();
+        neighbors
+    }
+
+
+    /// Neighbor ids of `id`, ordered by label.
+    pub(crate) fn sorted_neighbor_ids(&self, id: u32) -> Vec<u32> {
+        let mut neighbors: Vec<u32> = self.neighbor_ids(id).iter().copied().collect();
+        neighbors.sort_by(|a, b| self.label(*a).cmp(self.label(*b)));
+        neighbors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph_of(pairs: &[(&str, &str)]) -> Graph {
+        Graph::from_edges(pairs.iter().map(|(a, b)| (a.to_string(), b.to_string())))
+    }
+
+    #[test]
+    fn test_graph_construction() {
+        let mut graph = Graph::new();
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("A".to_string(), "C".to_string());
+
+        assert_eq!(graph.degree(&"A".to_string()), 2);
+        assert_eq!(graph.degree(&"B".to_string()), 1);
+    }
+
+    #[test]
+    fn test_degree_distribution() {
+        let mut graph = Graph::new();
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("A".to_string(), "C".to_string());
+
+        let distribution = graph.degree_distribution();
+        assert_eq!(distribution[&2], 1);
+        assert_eq!(distribution[&1], 2);
+    }
+
+    #[test]
+    fn test_degree_distribution_cache_invalidates() {
+        let mut graph = graph_of(&[("A", "B"), ("B", "C")]);
+        assert_eq!(graph.degree_distribution(), HashMap::from([(1, 2), (2, 1)]));
+        assert!(graph.degree_cache.get().is_some());
+        assert_eq!(graph.degree_distribution(), HashMap::from([(1, 2), (2, 1)]));
+
+        graph.add_edge("C".to_string(), "A".to_string());
+        assert_eq!(graph.degree_distribution(), HashMap::from([(2, 3)]));
+        graph.add_edge("D".to_string(), "D".to_string());
+        assert_eq!(graph.degree_distribution(), HashMap::from([(2, 3), (1, 1)]));
+        graph.remove_self_loops();
+        assert_eq!(graph.degree_distribution(), HashMap::from([(2, 3), (0, 1)]));
+        graph.remove_edge(&"A".to_string(), &"B".to_string());
+        assert_eq!(graph.degree_distribution(), HashMap::from([(1, 2), (2, 1), (0, 1)]));
+        graph.remove_node(&"C".to_string());
+        assert_eq!(graph.degree_distribution(), HashMap::from([(0, 3)]));
+
+        let copy = graph.clone();
+        graph.add_edge("A".to_string(), "B".to_string());
+        assert_eq!(copy.degree_distribution(), HashMap::from([(0, 3)]));
+        assert_eq!(graph.degree_distribution(), HashMap::from([(1, 2), (0, 1)]));
+    }
+
+    #[test]
+    fn test_degree_distribution_normalized() {
+        let graph = graph_of(&[("A", "B"), ("A", "C"), ("A", "D")]);
+        assert_eq!(graph.degree_distribution_normalized(), HashMap::from([(1, 0.75), (3, 0.25)]));
+        assert!(Graph::<String>::new().degree_distribution_normalized().is_empty());
+    }
+
+    #[test]
+    fn test_degree_ccdf() {
+        let graph = graph_of(&[("A", "B"), ("A", "C"), ("A", "D")]);
+        assert_eq!(graph.degree_ccdf(), vec![(1, 1.0), (3, 0.25)]);
+        assert!(Graph::<String>::new().degree_ccdf().is_empty());
+    }
+
+    #[test]
+    fn test_degree_loglog_points() {
+        let mut graph = graph_of(&[("A", "B"), ("A", "C"), ("A", "D")]);
+        graph.add_edge("E".to_string(), "F".to_string());
+        graph.remove_edge(&"E".to_string(), &"F".to_string());
+
+        let points = graph.degree_loglog_points();
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0], (0.0, 0.5f64.ln()));
+        assert!((points[1].0 - 3f64.ln()).abs() < 1e-12 && (points[1].1 - (1.0f64 / 6.0).ln()).abs() < 1e-12);
+        assert!(Graph::<String>::new().degree_loglog_points().is_empty());
+    }
+
+    #[test]
+    fn test_hubs_and_top_k_by_degree() {
+        let graph = graph_of(&[("H", "A"), ("H", "B"), ("H", "C"), ("G", "A"), ("G", "B"), ("A", "B"), ("X", "Y")]);
+        assert_eq!(graph.hubs(3), vec!["A", "B", "H"]);
+        assert_eq!(graph.hubs(2), vec!["A", "B", "H", "G"]);
+        assert!(graph.hubs(4).is_empty());
+        assert_eq!(graph.top_k_by_degree(2), vec!["A", "B"]);
+        assert_eq!(graph.top_k_by_degree(100).len(), graph.node_count());
+    }
+
+    #[test]
+    fn test_degree_stats() {
+        let star = graph_of(&[("A", "B"), ("A", "C"), ("A", "D")]).degree_stats().unwrap();
+        assert_eq!((star.min, star.max, star.mean, star.median), (1, 3, 1.5, 1.0));
+        assert!((star.std_dev - 0.75f64.sqrt()).abs() < 1e-12);
+
+        let path = graph_of(&[("A", "B"), ("B", "C"), ("C", "D")]).degree_stats().unwrap();
+        assert_eq!(path, DegreeStats { min: 1, max: 2, mean: 1.5, median: 1.5, std_dev: 0.5 });
+        assert_eq!(Graph::<String>::new().degree_stats(), None);
+    }
+
+    #[test]
+    fn test_neighbors_at_distance_two() {
+        let mut graph = Graph::new();
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("B".to_string(), "C".to_string());
+        graph.add_edge("C".to_string(), "D".to_string());
+
+        // On the path A-B-C-D, A reaches only C and B reaches only D in two hops.
+        assert_eq!(graph.neighbors_at_distance_two(&"A".to_string()), 1);
+        assert_eq!(graph.neighbors_at_distance_two(&"B".to_string()), 1);
+    }
+
+    #[test]
+    fn test_neighbors_at_distance_two_set() {
+        // In the triangle A-B-C with a tail C-D, B and C are direct neighbors
+        // of A even though each is also two hops away through the other.
+        let graph = graph_of(&[("A", "B"), ("B", "C"), ("C", "A"), ("C", "D"), ("D", "E")]);
+        assert_eq!(graph.neighbors_at_distance_two_set(&"A".to_string()), HashSet::from(["D".to_string()]));
+        assert_eq!(graph.neighbors_at_distance_two(&"A".to_string()), 1);
+        let expected: HashSet<String> = ["A", "B"].iter().map(|node| node.to_string()).collect();
+        assert_eq!(graph.neighbors_at_distance_two_set(&"E".to_string()), HashSet::from(["C".to_string()]));
+        assert_eq!(graph.neighbors_at_distance_two_set(&"D".to_string()), expected);
+        assert!(graph.neighbors_at_distance_two_set(&"Q".to_string()).is_empty());
+    }
+
+    #[test]
+    fn test_neighbors_at_distance_k() {
+        let mut graph = Graph::new();
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("B".to_string(), "C".to_string());
+        graph.add_edge("C".to_string(), "D".to_string());
+        graph.add_edge("A".to_string(), "C".to_string());
+
+        let a = "A".to_string();
+        assert_eq!(graph.neighbors_at_distance_k(&a, 0), 0);
+        assert_eq!(graph.neighbors_at_distance_k(&a, 1), 2);
+        assert_eq!(graph.neighbors_at_distance_k(&a, 2), 1);
+        assert_eq!(graph.neighbors_at_distance_k(&a, 3), 0);
+        assert_eq!(graph.neighbors_at_distance_k(&"Z".to_string(), 1), 0);
+    }
+
+    #[test]
+    fn test_weighted_edges() {
+        let mut graph = Graph::new();
+        graph.add_weighted_edge("A".to_string(), "B".to_string(), 2.5);
+        graph.add_weighted_edge("B".to_string(), "A".to_string(), 1.0);
+        graph.add_edge("B".to_string(), "C".to_string());
+
+        assert_eq!(graph.edge_weight(&"A".to_string(), &"B".to_string()), Some(3.5));
+        assert_eq!(graph.edge_weight(&"C".to_string(), &"B".to_string()), Some(1.0));
+        assert_eq!(graph.edge_weight(&"A".to_string(), &"C".to_string()), None);
+        assert_eq!(graph.degree(&"B".to_string()), 2);
+    }
+
+    #[test]
+    fn test_remove_edge_and_node() {
+        let mut graph = Graph::new();
+        graph.add_weighted_edge("A".to_string(), "B".to_string(), 2.0);
+        graph.add_edge("A".to_string(), "C".to_string());
+        graph.add_edge("B".to_string(), "C".to_string());
+        let a = "A".to_string();
+        let b = "B".to_string();
+
+        graph.remove_edge(&b, &a);
+        assert_eq!(graph.edge_weight(&a, &b), None);
+        assert_eq!(graph.degree_distribution(), HashMap::from([(1, 2), (2, 1)]));
+
+        graph.remove_node(&"C".to_string());
+        assert_eq!(graph.degree_distribution(), HashMap::from([(0, 2)]));
+
+        graph.remove_edge(&a, &"Z".to_string());
+        graph.remove_node(&"Z".to_string());
+        assert_eq!(graph.node_count(), 2);
+    }
+
+    #[test]
+    fn test_only_explicit_weights_accumulate() {
+        let (a, b) = ("A".to_string(), "B".to_string());
+        let mut unweighted = Graph::new();
+        unweighted.add_edge(a.clone(), b.clone());
+        unweighted.add_edge(b.clone(), a.clone());
+        assert_eq!(unweighted.edge_weight(&a, &b), Some(1.0));
+        let mut weighted = Graph::new();
+        weighted.add_weighted_edge(a.clone(), b.clone(), 1.0);
+        weighted.add_weighted_edge(b.clone(), a.clone(), 1.0);
+        assert_eq!(weighted.edge_weight(&a, &b), Some(2.0));
+        weighted.add_edge(a.clone(), b.clone());
+        assert_eq!(weighted.edge_weight(&a, &b), Some(2.0));
+
+        // merge follows the same rule as adding the other graph's edges.
+        let mut merged = unweighted.clone();
+        merged.merge(&weighted);
+        assert_eq!(merged.edge_weight(&a, &b), Some(2.0));
+        let mut merged = unweighted.clone();
+        merged.merge(&unweighted);
+        assert_eq!(merged.edge_weight(&a, &b), Some(1.0));
+    }
+
+    #[test]
+    fn test_merge_and_union() {
+        let mut monday = Graph::new();
+        monday.add_edge("A".to_string(), "B".to_string());
+        monday.add_weighted_edge("B".to_string(), "C".to_string(), 2.0);
+        let mut tuesday = Graph::new();
+        tuesday.add_edge("B".to_string(), "A".to_string());
+        tuesday.add_weighted_edge("C".to_string(), "B".to_string(), 0.5);
+        tuesday.add_edge("C".to_string(), "D".to_string());
+        tuesday.add_edge("E".to_string(), "F".to_string());
+        tuesday.remove_edge(&"E".to_string(), &"F".to_string());
+
+        let mut combined = Graph::new();
+        for (a, b) in [("A", "B"), ("B", "A"), ("C", "D")] {
+            combined.add_edge(a.to_string(), b.to_string());
+        }
+        combined.add_weighted_edge("B".to_string(), "C".to_string(), 2.0);
+        combined.add_weighted_edge("C".to_string(), "B".to_string(), 0.5);
+        combined.add_edge("E".to_string(), "F".to_string());
+        combined.remove_edge(&"E".to_string(), &"F".to_string());
+
+        let merged = Graph::union(monday.clone(), tuesday.clone());
+        assert_eq!(merged.degree_distribution(), combined.degree_distribution());
+        assert_eq!(merged.edge_count(), 3);
+        assert_eq!(merged.edge_weight(&"B".to_string(), &"C".to_string()), Some(2.5));
+
+        monday.merge(&tuesday);
+        assert_eq!(monday.node_count(), 6);
+    }
+
+    #[test]
+    fn test_induced_subgraph() {
+        let mut graph = Graph::new();
+        graph.add_weighted_edge("A".to_string(), "B".to_string(), 3.0);
+        graph.add_edge("B".to_string(), "C".to_string());
+        graph.add_edge("C".to_string(), "A".to_string());
+        graph.add_edge("C".to_string(), "D".to_string());
+
+        let keep: HashSet<String> = ["A", "B", "D", "Z"].iter().map(|node| node.to_string()).collect();
+        let subgraph = graph.induced_subgraph(&keep);
+
+        assert_eq!(subgraph.node_count(), 3);
+        assert_eq!(subgraph.edge_count(), 1);
+        assert_eq!(subgraph.edge_weight(&"B".to_string(), &"A".to_string()), Some(3.0));
+        assert_eq!(subgraph.degree(&"D".to_string()), 0);
+        assert_eq!(graph.edge_count(), 4);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let mut graph = Graph::new();
+        graph.add_edge("B".to_string(), "C".to_string());
+        graph.add_edge("B".to_string(), "A".to_string());
+        let b = "B".to_string();
+
+        let mut neighbors: Vec<&String> = graph.neighbors(&b).collect();
+        neighbors.sort();
+        assert_eq!(neighbors, vec!["A", "C"]);
+        assert_eq!(graph.sorted_neighbors(&b), vec!["A", "C"]);
+        assert_eq!(graph.neighbors(&"Z".to_string()).count(), 0);
+    }
+
+    #[test]
+    fn test_nodes_and_edges() {
+        let mut graph = Graph::new();
+        graph.add_edge("C".to_string(), "A".to_string());
+        graph.add_edge("B".to_string(), "A".to_string());
+        graph.add_edge("B".to_string(), "B".to_string());
+        graph.add_edge("D".to_string(), "E".to_string());
+        graph.remove_edge(&"D".to_string(), &"E".to_string());
+
+        assert_eq!(graph.nodes().count(), 5);
+        assert_eq!(graph.sorted_nodes(), vec!["A", "B", "C", "D", "E"]);
+        let edges: Vec<(String, String)> = graph.edges().collect();
+        let expected = [("A", "B"), ("A", "C"), ("B", "B")];
+        assert_eq!(edges, expected.map(|(a, b)| (a.to_string(), b.to_string())));
+    }
+
+    #[test]
+    fn test_from_edges() {
+        let pairs = [("A", "B"), ("B", "C"), ("A", "B"), ("D", "D")];
+        let graph = Graph::from_edges(pairs.map(|(a, b)| (a.to_string(), b.to_string())));
+
+        let mut expected = Graph::new();
+        for (a, b) in pairs {
+            expected.add_edge(a.to_string(), b.to_string());
+        }
+        assert_eq!(graph.to_json(), expected.to_json());
+        assert_eq!(graph.edge_count(), 3);
+    }
+
+    #[test]
+    fn test_add_edges() {
+        let pairs: Vec<(String, String)> = [("A", "B"), ("B", "C"), ("C", "A"), ("A", "B"), ("D", "D")]
+            .map(|(a, b)| (a.to_string(), b.to_string()))
+            .to_vec();
+        let mut graph = Graph::new();
+        graph.add_edge("Z".to_string(), "A".to_string());
+        graph.add_edges(&pairs);
+
+        let mut expected = Graph::new();
+        expected.add_edge("Z".to_string(), "A".to_string());
+        for (a, b) in pairs {
+            expected.add_edge(a, b);
+        }
+        assert_eq!(graph.to_json(), expected.to_json());
+        assert_eq!(graph.degree_distribution(), expected.degree_distribution());
+    }
+
+    #[test]
+    fn test_multigraph() {
+        let (a, b, c) = ("A".to_string(), "B".to_string(), "C".to_string());
+        let mut graph = Graph::new_multigraph();
+        graph.add_edge(a.clone(), b.clone());
+        graph.add_edge(b.clone(), a.clone());
+        graph.add_weighted_edge(a.clone(), b.clone(), 2.0);
+        graph.add_edge(a.clone(), c.clone());
+        graph.add_edge("Z".to_string(), "Z".to_string());
+
+        assert_eq!(graph.edge_multiplicity(&a, &b), 3);
+        assert_eq!(graph.edge_multiplicity(&a, &c), 1);
+        assert_eq!(graph.edge_multiplicity(&b, &c), 0);
+        assert_eq!(graph.parallel_edge_count(), 2);
+        assert_eq!(graph.degree(&a), 2);
+        assert_eq!(graph.degree_with_multiplicity(&a), 4);
+        assert_eq!(graph.edge_count(), 3);
+
+        graph.remove_node(&a);
+        assert_eq!(graph.parallel_edge_count(), 0);
+
+        let mut simple = Graph::new();
+        simple.add_edge(a.clone(), b.clone());
+        simple.add_edge(a.clone(), b.clone());
+        assert_eq!(simple.edge_multiplicity(&a, &b), 1);
+        assert_eq!(simple.degree_with_multiplicity(&a), 1);
+    }
+
+    #[test]
+    fn test_multigraph_survives_remove_node_and_merge() {
+        let (a, b, c) = ("A".to_string(), "B".to_string(), "C".to_string());
+        let mut graph = Graph::new_multigraph();
+        graph.add_edge(a.clone(), b.clone());
+        graph.add_edge(b.clone(), c.clone());
+        graph.add_edge(c.clone(), b.clone());
+        graph.add_edge(c.clone(), c.clone());
+        graph.add_edge(c.clone(), c.clone());
+        // C takes over A's id here.
+        graph.remove_node(&a);
+        assert_eq!(graph.edge_multiplicity(&b, &c), 2);
+        assert_eq!(graph.edge_multiplicity(&c, &c), 2);
+
+        let merged = Graph::union(graph.clone(), graph);
+        assert_eq!(merged.edge_multiplicity(&b, &c), 4);
+        assert_eq!(merged.edge_multiplicity(&c, &c), 4);
+    }
+
+    #[test]
+    fn test_complement() {
+        let mut graph = graph_of(&[("A", "B"), ("B", "C"), ("C", "C")]);
+        graph.add_edge("D".to_string(), "A".to_string());
+        graph.remove_edge(&"D".to_string(), &"A".to_string());
+
+        let complement = graph.complement();
+        assert_eq!(complement.node_count(), 4);
+        // 6 possible pairs minus A-B and B-C; the self-loop is not complemented.
+        assert_eq!(complement.edge_count(), 4);
+        assert!(complement.has_edge(&"A".to_string(), &"C".to_string()));
+        assert!(!complement.has_edge(&"C".to_string(), &"C".to_string()));
+        assert_eq!(complement.degree(&"D".to_string()), 3);
+        assert_eq!(Graph::<String>::new().complement().node_count(), 0);
+    }
+
+    #[test]
+    fn test_reverse_is_a_clone() {
+        let mut graph = graph_of(&[("A", "B"), ("B", "C")]);
+        graph.add_weighted_edge("C".to_string(), "D".to_string(), 2.0);
+        let reversed = graph.reverse();
+        assert!(reversed == graph);
+        assert!(reversed.reverse() == graph);
+        assert_eq!(reversed.edge_weight(&"D".to_string(), &"C".to_string()), Some(2.0));
+    }
+
+    #[test]
+    fn test_graph_equality() {
+        let graph = graph_of(&[("A", "B"), ("B", "C"), ("C", "C")]);
+        let reordered = graph_of(&[("C", "C"), ("C", "B"), ("B", "A")]);
+        assert!(graph == reordered);
+
+        let mut with_isolated = reordered.clone();
+        with_isolated.add_edge("D".to_string(), "A".to_string());
+        with_isolated.remove_edge(&"D".to_string(), &"A".to_string());
+        assert!(graph != with_isolated);
+        with_isolated.remove_node(&"D".to_string());
+        assert!(graph == with_isolated);
+
+        assert!(graph != graph_of(&[("A", "B"), ("B", "C"), ("C", "A")]));
+        assert!(Graph::<String>::new() == Graph::new());
+    }
+
+    #[test]
+    fn test_node_attributes() {
+        let mut graph = graph_of(&[("A", "B"), ("B", "C")]);
+        let (a, b, c) = ("A".to_string(), "B".to_string(), "C".to_string());
+        graph.set_node_attribute(&a, "flagged".to_string(), "true".to_string());
+        graph.set_node_attribute(&c, "country".to_string(), "NZ".to_string());
+        graph.set_node_attribute(&c, "country".to_string(), "AU".to_string());
+        graph.set_node_attribute(&"Z".to_string(), "note".to_string(), "new".to_string());
+
+        assert_eq!(graph.get_node_attribute(&a, "flagged"), Some(&"true".to_string()));
+        assert_eq!(graph.get_node_attribute(&c, "country"), Some(&"AU".to_string()));
+        assert_eq!(graph.get_node_attribute(&b, "flagged"), None);
+        assert_eq!(graph.degree(&"Z".to_string()), 0);
+
+        let kept: HashSet<String> = [a.clone(), b.clone()].into_iter().collect();
+        let subgraph = graph.induced_subgraph(&kept);
+        assert_eq!(subgraph.get_node_attribute(&a, "flagged"), Some(&"true".to_string()));
+        assert_eq!(subgraph.get_node_attribute(&c, "country"), None);
+
+        // A's id goes to the last node, Z, whose attributes must follow it.
+        graph.remove_node(&a);
+        assert_eq!(graph.get_node_attribute(&a, "flagged"), None);
+        assert_eq!(graph.get_node_attribute(&"Z".to_string(), "note"), Some(&"new".to_string()));
+    }
+
+    #[test]
+    fn test_isolated_and_low_degree_nodes() {
+        let mut graph = graph_of(&[("A", "B"), ("A", "C"), ("A", "D"), ("E", "E"), ("Y", "Z")]);
+        graph.remove_edge(&"Y".to_string(), &"Z".to_string());
+
+        assert_eq!(graph.isolated_nodes(), vec!["Y", "Z"]);
+        assert_eq!(graph.nodes_with_degree_below(2), vec!["B", "C", "D", "E", "Y", "Z"]);
+        assert!(graph.nodes_with_degree_below(0).is_empty());
+    }
+
+    #[test]
+    fn test_has_edge_and_contains_node() {
+        let mut graph = Graph::new();
+        graph.add_edge("A".to_string(), "B".to_string());
+        let a = "A".to_string();
+        let b = "B".to_string();
+        let z = "Z".to_string();
+
+        assert!(graph.has_edge(&a, &b));
+        assert!(graph.has_edge(&b, &a));
+        assert!(!graph.has_edge(&a, &a));
+        assert!(!graph.has_edge(&a, &z));
+        assert!(graph.contains_node(&b));
+        assert!(!graph.contains_node(&z));
+    }
+
+    #[test]
+    fn test_node_and_edge_count() {
+        let mut graph = Graph::new();
+        assert_eq!(graph.node_count(), 0);
+        assert_eq!(graph.edge_count(), 0);
+
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("B".to_string(), "A".to_string());
+        graph.add_edge("B".to_string(), "C".to_string());
+        graph.add_edge("C".to_string(), "C".to_string());
+
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.edge_count(), 3);
+    }
+
+    #[test]
+    fn test_degree() {
+        let mut graph = Graph::new();
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("A".to_string(), "C".to_string());
+
+        assert_eq!(graph.degree(&"A".to_string()), 2);
+        assert_eq!(graph.degree(&"B".to_string()), 1);
+        assert_eq!(graph.degree(&"Z".to_string()), 0);
+        let distribution = graph.degree_distribution();
+        assert_eq!(distribution[&graph.degree(&"A".to_string())], 1);
+    }
+
+    #[test]
+    fn test_remove_self_loops() {
+        let mut graph = Graph::new();
+        graph.add_weighted_edge("A".to_string(), "A".to_string(), 4.0);
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("C".to_string(), "C".to_string());
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(graph.degree(&"A".to_string()), 2);
+
+        graph.remove_self_loops();
+        assert_eq!(graph.edge_count(), 1);
+        assert_eq!(graph.degree(&"A".to_string()), 1);
+        assert_eq!(graph.degree(&"C".to_string()), 0);
+        assert!(graph.weights.is_empty());
+    }
+
+    #[test]
+    fn test_density() {
+        let mut graph = Graph::new();
+        assert_eq!(graph.density(), 0.0);
+        graph.add_edge("A".to_string(), "A".to_string());
+        assert_eq!(graph.density(), 0.0);
+
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("B".to_string(), "C".to_string());
+        graph.remove_self_loops();
+        assert!((graph.density() - 2.0 / 3.0).abs() < 1e-12);
+        graph.add_edge("A".to_string(), "C".to_string());
+        assert_eq!(graph.density(), 1.0);
+    }
+
+    #[test]
+    fn test_integer_nodes() {
+        let mut graph: Graph<u64> = Graph::new();
+        graph.add_edge(1, 2);
+        graph.add_weighted_edge(2, 3, 4.0);
+        graph.add_edge(3, 1);
+
+        assert_eq!(graph.degree(&1), 2);
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(graph.edge_weight(&3, &2), Some(4.0));
+        assert_eq!(graph.degree_distribution(), HashMap::from([(2, 3)]));
+        assert_eq!(graph.shortest_path(&1, &3), Some(vec![1, 3]));
+        assert_eq!(graph.triangle_count(), 1);
+
+        graph.remove_edge(&2, &3);
+        assert_eq!(graph.edge_weight(&2, &3), None);
+        assert!(graph.weights.is_empty());
+    }
+}
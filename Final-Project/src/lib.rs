@@ -0,0 +1,36 @@
+mod centrality;
+mod clustering;
+mod cohesion;
+mod community;
+mod components;
+mod digraph;
+mod export;
+mod generators;
+mod graph;
+mod loader;
+mod mixing;
+#[cfg(feature = "parallel")]
+mod parallel;
+mod paths;
+mod persist;
+mod power_law;
+mod report;
+mod rng;
+mod similarity;
+mod spanning;
+mod structure;
+mod temporal;
+
+pub use digraph::DiGraph;
+pub use graph::{DegreeStats, Graph};
+pub use loader::{
+    build_graph_from_csv, build_graph_from_csv_columns, build_graph_from_csv_with_options, build_graph_from_delimited,
+    build_graph_from_reader, build_graph_from_reader_with_options, build_temporal_graph_from_csv, CsvOptions,
+    LoadStats,
+};
+pub use paths::NegativeWeightError;
+pub use power_law::{
+    evaluate_fitted_power_law, evaluate_power_law, evaluate_power_law_with, fit_power_law_exponent, ks_statistic,
+};
+pub use report::MetricsReport;
+pub use temporal::TemporalGraph;
@@ -0,0 +1,489 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+use flate2::read::GzDecoder;
+
+use crate::graph::Graph;
+use crate::temporal::TemporalGraph;
+
+
+/// Bookkeeping gathered while loading an edge list.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct LoadStats {
+    /// 1-based line numbers of rows that could not be turned into an edge.
+    pub skipped_lines: Vec<usize>,
+    /// Rows whose two endpoints were the same node, whether kept or skipped.
+    pub self_loop_rows: usize,
+    /// Rows repeating an edge that an earlier row already added.
+    pub duplicate_rows: usize,
+}
+
+
+/// What became of a parsed row.
+enum RowOutcome {
+    Added,
+    Duplicate,
+    Malformed,
+}
+
+
+/// Options for the CSV loaders; the default reads comma-separated rows with no header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvOptions {
+    pub delimiter: char,
+    /// Discard the first line instead of reading it as an edge.
+    pub has_header: bool,
+    /// Ignore rows whose two endpoints are the same node.
+    pub skip_self_loops: bool,
+    /// Zero-based columns holding the two endpoints.
+    pub node_columns: (usize, usize),
+    /// Zero-based column read as the edge weight, if any.
+    pub weight_column: Option<usize>,
+    /// Load into a multigraph so repeated rows raise `edge_multiplicity`.
+    pub multigraph: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: ',',
+            has_header: false,
+            skip_self_loops: false,
+            node_columns: (0, 1),
+            weight_column: Some(2),
+            multigraph: false,
+        }
+    }
+}
+
+
+/// Builds an undirected graph from the first two columns of a CSV file.
+///
+/// Equivalent to `build_graph_from_csv_with_options` with default options.
+pub fn build_graph_from_csv(file_path: &str) -> io::Result<(Graph, LoadStats)> {
+    build_graph_from_csv_with_options(file_path, &CsvOptions::default())
+}
+
+
+/// Builds an undirected graph whose endpoints come from the zero-based
+/// columns `col1` and `col2`. The weight is read from the third column as in
+/// `build_graph_from_csv`, so columns 0 and 1 load the same graph as it does.
+pub fn build_graph_from_csv_columns(file_path: &str, col1: usize, col2: usize) -> io::Result<(Graph, LoadStats)> {
+    let options = CsvOptions {
+        node_columns: (col1, col2),
+        ..CsvOptions::default()
+    };
+    build_graph_from_csv_with_options(file_path, &options)
+}
+
+
+/// Builds an undirected graph from the first two `delimiter`-separated columns.
+pub fn build_graph_from_delimited(file_path: &str, delimiter: char) -> io::Result<(Graph, LoadStats)> {
+    let options = CsvOptions {
+        delimiter,
+        ..CsvOptions::default()
+    };
+    build_graph_from_csv_with_options(file_path, &options)
+}
+
+
+/// Builds an undirected graph from the first two columns of a delimited file,
+/// parsed as by `build_graph_from_reader_with_options`.
+///
+/// Fails if the file cannot be opened or read. Files ending in `.gz` are
+/// decompressed on the fly.
+pub fn build_graph_from_csv_with_options(file_path: &str, options: &CsvOptions) -> io::Result<(Graph, LoadStats)> {
+    build_graph_from_reader_with_options(open_edge_list(file_path)?, options)
+}
+
+
+/// Opens `file_path` for buffered reading, decompressing it on the fly when
+/// the name ends in `.gz`.
+fn open_edge_list(file_path: &str) -> io::Result<Box<dyn BufRead>> {
+    let file = File::open(file_path)?;
+    if Path::new(file_path).extension().is_some_and(|extension| extension == "gz") {
+        Ok(Box::new(BufReader::new(GzDecoder::new(file))))
+    } else {
+        Ok(Box::new(BufReader::new(file)))
+    }
+}
+
+
+/// Splits one record on `delimiter`, trimming unquoted fields. A field that
+/// starts with `"` runs to the matching closing quote and may contain the
+/// delimiter; `""` inside it stands for one quote (RFC 4180). Returns `None`
+/// for an unterminated quote or text after a closing quote.
+fn split_fields(record: &str, delimiter: char) -> Option<Vec<String>> {
+    let mut fields = Vec::new();
+    let mut chars = record.chars().peekable();
+    loop {
+        while chars.next_if(|&c| c != delimiter && c.is_whitespace()).is_some() {}
+
+        let mut field = String::new();
+        if chars.next_if_eq(&'"').is_some() {
+            loop {
+                match chars.next()? {
+                    '"' if chars.next_if_eq(&'"').is_some() => field.push('"'),
+                    '"' => break,
+                    c => field.push(c),
+                }
+            }
+            while chars.next_if(|&c| c != delimiter && c.is_whitespace()).is_some() {}
+            if chars.peek().is_some_and(|&c| c != delimiter) {
+                return None;
+            }
+        } else {
+            while let Some(c) = chars.next_if(|&c| c != delimiter) {
+                field.push(c);
+            }
+            field.truncate(field.trim_end().len());
+        }
+        fields.push(field);
+
+        if chars.next().is_none() {
+            return Some(fields);
+        }
+    }
+}
+
+
+/// Builds a graph from any buffered source, such as stdin or a socket, with
+/// default options.
+pub fn build_graph_from_reader<R: BufRead>(reader: R) -> io::Result<(Graph, LoadStats)> {
+    build_graph_from_reader_with_options(reader, &CsvOptions::default())
+}
+
+
+/// Builds an undirected graph from the `node_columns` of each line (by
+/// default the first two).
+///
+/// The `weight_column` (by default the third) is read as the edge weight;
+/// rows where it is missing or empty weigh 1.0. Every row adds its weight,
+/// so a repeated edge weighs the sum of its rows whether or not they carry
+/// a weight. Fields may be double-quoted to contain the delimiter.
+///
+/// Fails only if reading fails. Rows that are not valid UTF-8, lack either
+/// node column, have an empty node name, or have a weight that is not a
+/// number are skipped and reported in the returned `LoadStats`; blank lines
+/// are ignored. Line numbers count the header, if any.
+///
+/// Input is consumed one line at a time, so peak memory is the size of the
+/// graph plus one line, not the size of the input.
+pub fn build_graph_from_reader_with_options<R: BufRead>(
+    reader: R,
+    options: &CsvOptions,
+) -> io::Result<(Graph, LoadStats)> {
+    let mut graph = if options.multigraph { Graph::new_multigraph() } else { Graph::new() };
+    let stats = for_each_edge(reader, options, |node1, node2, weight, _| {
+        let duplicate = graph.has_edge(&node1, &node2);
+        graph.add_edge_row(node1, node2, weight.unwrap_or(1.0));
+        if duplicate {
+            RowOutcome::Duplicate
+        } else {
+            RowOutcome::Added
+        }
+    })?;
+    Ok((graph, stats))
+}
+
+
+/// Builds a `TemporalGraph` using the same row rules as
+/// `build_graph_from_reader_with_options`, reading each edge's time as an
+/// integer from `timestamp_column`. Rows without a valid timestamp are
+/// skipped and reported; weights are not read.
+pub fn build_temporal_graph_from_csv(
+    file_path: &str,
+    options: &CsvOptions,
+    timestamp_column: usize,
+) -> io::Result<(TemporalGraph, LoadStats)> {
+    let reader = open_edge_list(file_path)?;
+    let mut temporal = TemporalGraph::new();
+    let add = |node1, node2, _, fields: &[String]| {
+        match fields.get(timestamp_column).and_then(|field| field.parse::<u64>().ok()) {
+            Some(timestamp) => {
+                temporal.add_edge(node1, node2, timestamp);
+                RowOutcome::Added
+            }
+            None => RowOutcome::Malformed,
+        }
+    };
+    let options = CsvOptions { weight_column: None, ..options.clone() };
+    let stats = for_each_edge(reader, &options, add)?;
+    Ok((temporal, stats))
+}
+
+
+/// Parses each row and hands its endpoints, weight and remaining fields to
+/// `add`, which reports what it did with them.
+fn for_each_edge<R, F>(reader: R, options: &CsvOptions, mut add: F) -> io::Result<LoadStats>
+where
+    R: BufRead,
+    F: FnMut(String, String, Option<f64>, &[String]) -> RowOutcome,
+{
+    let mut lines = reader.lines().enumerate();
+    if options.has_header {
+        if let Some((_, Err(e))) = lines.next() {
+            if e.kind() != io::ErrorKind::InvalidData {
+                return Err(e);
+            }
+        }
+    }
+
+    let mut stats = LoadStats::default();
+
+    for (index, line) in lines {
+        let line_number = index + 1;
+        let record = match line {
+            Ok(record) => record,
+            Err(e) if e.kind() == io::ErrorKind::InvalidData => {
+                stats.skipped_lines.push(line_number);
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        if record.trim().is_empty() {
+            continue;
+        }
+
+        let (column1, column2) = options.node_columns;
+        let mut parts = match split_fields(&record, options.delimiter) {
+            Some(parts) if parts.len() > column1.max(column2) => parts,
+            _ => {
+                stats.skipped_lines.push(line_number);
+                continue;
+            }
+        };
+        let weight = match options.weight_column.and_then(|column| parts.get(column)) {
+            Some(field) if !field.is_empty() => match field.parse::<f64>() {
+                Ok(weight) => Some(weight),
+                Err(_) => {
+                    stats.skipped_lines.push(line_number);
+                    continue;
+                }
+            },
+            _ => None,
+        };
+        let node1 = std::mem::take(&mut parts[column1]);
+        let node2 = if column2 == column1 { node1.clone() } else { std::mem::take(&mut parts[column2]) };
+        if node1.is_empty() || node2.is_empty() {
+            stats.skipped_lines.push(line_number);
+            continue;
+        }
+        if node1 == node2 {
+            stats.self_loop_rows += 1;
+            if options.skip_self_loops {
+                continue;
+            }
+        }
+        match add(node1, node2, weight, &parts) {
+            RowOutcome::Added => {}
+            RowOutcome::Duplicate => stats.duplicate_rows += 1,
+            RowOutcome::Malformed => stats.skipped_lines.push(line_number),
+        }
+    }
+
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::path::PathBuf;
+
+    fn write_temp_csv(name: &str, contents: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("degree_distribution_{}_{}", std::process::id(), name));
+        let mut file = File::create(&path).unwrap();
+        file.write_all(contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_missing_file_is_an_error() {
+        let result = build_graph_from_csv("./definitely_not_here.csv");
+        assert_eq!(result.err().unwrap().kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_malformed_rows_are_reported() {
+        let path = write_temp_csv("malformed.csv", b"A,B\nlonely\n\nB,C\n,D\n\xff\xfe,E\n");
+        let (graph, stats) = build_graph_from_csv(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.degree(&"B".to_string()), 2);
+        assert_eq!(stats.skipped_lines, vec![2, 5, 6]);
+    }
+
+    #[test]
+    fn test_third_column_is_read_as_weight() {
+        let path = write_temp_csv("weighted.csv", b"A,B,2.5\nB,A,1.5\nB,C\nC,D,note\n");
+        let (graph, stats) = build_graph_from_csv(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(stats.skipped_lines, vec![4]);
+        assert_eq!(graph.edge_weight(&"A".to_string(), &"B".to_string()), Some(4.0));
+        assert_eq!(graph.edge_weight(&"B".to_string(), &"C".to_string()), Some(1.0));
+        assert_eq!(graph.edge_weight(&"C".to_string(), &"D".to_string()), None);
+    }
+
+    #[test]
+    fn test_repeated_rows_add_their_weights() {
+        let path = write_temp_csv("mixed_weights.csv", b"A,B\nA,B,2\nC,D,2\nC,D,\nE,F\nF,E\nG,H,1\nG,H,1\n");
+        let (graph, stats) = build_graph_from_csv(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(stats.duplicate_rows, 4);
+        for (a, b) in [("A", "B"), ("C", "D")] {
+            assert_eq!(graph.edge_weight(&a.to_string(), &b.to_string()), Some(3.0));
+        }
+        for (a, b) in [("E", "F"), ("G", "H")] {
+            assert_eq!(graph.edge_weight(&a.to_string(), &b.to_string()), Some(2.0));
+        }
+    }
+
+    #[test]
+    fn test_custom_delimiter() {
+        let path = write_temp_csv("tabs.tsv", b"A\tB\t3\nB\tC\nA,C\n");
+        let (graph, stats) = build_graph_from_delimited(path.to_str().unwrap(), '\t').unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(graph.edge_count(), 2);
+        assert_eq!(graph.edge_weight(&"A".to_string(), &"B".to_string()), Some(3.0));
+        assert_eq!(stats.skipped_lines, vec![3]);
+    }
+
+    #[test]
+    fn test_header_row_is_skipped_when_requested() {
+        let path = write_temp_csv("header.csv", b"source,target\nA,B\nB,C\n");
+        let file_path = path.to_str().unwrap();
+        let options = CsvOptions {
+            has_header: true,
+            ..CsvOptions::default()
+        };
+        let (with_header, _) = build_graph_from_csv_with_options(file_path, &options).unwrap();
+        let (without_header, _) = build_graph_from_csv(file_path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(!with_header.contains_node(&"source".to_string()));
+        assert_eq!(with_header.edge_count(), 2);
+        assert!(without_header.has_edge(&"source".to_string(), &"target".to_string()));
+    }
+
+    #[test]
+    fn test_self_loops_can_be_skipped() {
+        let path = write_temp_csv("loops.csv", b"A,A\nA,B\nC,C\n");
+        let options = CsvOptions {
+            skip_self_loops: true,
+            ..CsvOptions::default()
+        };
+        let (graph, stats) = build_graph_from_csv_with_options(path.to_str().unwrap(), &options).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(stats.skipped_lines.is_empty());
+        assert_eq!(stats.self_loop_rows, 2);
+        assert_eq!(graph.node_count(), 2);
+        assert_eq!(graph.degree(&"A".to_string()), 1);
+    }
+
+    #[test]
+    fn test_split_fields() {
+        let fields = |record: &str| split_fields(record, ',');
+        assert_eq!(fields(" A , B ,2").unwrap(), vec!["A", "B", "2"]);
+        assert_eq!(fields("\"Smith, Jane\",Acct123").unwrap(), vec!["Smith, Jane", "Acct123"]);
+        assert_eq!(fields("\"say \"\"hi\"\"\" , \"\"").unwrap(), vec!["say \"hi\"", ""]);
+        assert_eq!(fields("A,").unwrap(), vec!["A", ""]);
+        assert_eq!(split_fields("A\tB", '\t').unwrap(), vec!["A", "B"]);
+        assert!(fields("\"open,B").is_none());
+        assert!(fields("\"A\"x,B").is_none());
+    }
+
+    #[test]
+    fn test_quoted_fields_keep_embedded_delimiters() {
+        let input = io::Cursor::new("\"Smith, Jane\",Acct123\nAcct123,\"O\"\"Neil\",2\n\"broken,B\n");
+        let (graph, stats) = build_graph_from_reader(input).unwrap();
+
+        assert!(graph.has_edge(&"Smith, Jane".to_string(), &"Acct123".to_string()));
+        assert_eq!(graph.edge_weight(&"Acct123".to_string(), &"O\"Neil".to_string()), Some(2.0));
+        assert_eq!(stats.skipped_lines, vec![3]);
+    }
+
+    #[test]
+    fn test_node_columns_can_be_chosen() {
+        let path = write_temp_csv("columns.csv", b"t1,A,9,B\nt2,B,,C\nt3,D\n");
+        let (graph, stats) = build_graph_from_csv_columns(path.to_str().unwrap(), 1, 3).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.edge_weight(&"A".to_string(), &"B".to_string()), Some(9.0));
+        assert_eq!(graph.edge_weight(&"B".to_string(), &"C".to_string()), Some(1.0));
+        assert_eq!(stats.skipped_lines, vec![3]);
+    }
+
+    #[test]
+    fn test_first_two_columns_match_the_default_loader() {
+        let path = write_temp_csv("default_columns.csv", b"A,B,2.5\nB,C\nA,B,1\n");
+        let path_str = path.to_str().unwrap();
+        let (by_columns, column_stats) = build_graph_from_csv_columns(path_str, 0, 1).unwrap();
+        let (by_default, default_stats) = build_graph_from_csv(path_str).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(by_columns == by_default);
+        assert_eq!(by_columns.to_json(), by_default.to_json());
+        assert_eq!(column_stats, default_stats);
+        assert_eq!(by_columns.edge_weight(&"A".to_string(), &"B".to_string()), Some(3.5));
+    }
+
+    #[test]
+    fn test_build_temporal_graph_from_csv() {
+        let path = write_temp_csv("temporal.csv", b"A,B,100\nB,C,200\nC,D,later\n");
+        let options = CsvOptions::default();
+        let (temporal, stats) = build_temporal_graph_from_csv(path.to_str().unwrap(), &options, 2).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(temporal.edge_count(), 2);
+        assert_eq!(temporal.snapshot(150).edge_count(), 1);
+        assert_eq!(stats.skipped_lines, vec![3]);
+    }
+
+    #[test]
+    fn test_self_loop_and_duplicate_rows_are_counted() {
+        let input = io::Cursor::new("A,B\nB,A\nA,A\nA,A\nA,B,2\nB,C\n");
+        let (graph, stats) = build_graph_from_reader(input).unwrap();
+
+        assert_eq!(stats.self_loop_rows, 2);
+        assert_eq!(stats.duplicate_rows, 3);
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(graph.self_loop_count(), 1);
+
+        let options = CsvOptions {
+            multigraph: true,
+            ..CsvOptions::default()
+        };
+        let input = io::Cursor::new("A,B\nB,A\nA,B\n");
+        let (multigraph, stats) = build_graph_from_reader_with_options(input, &options).unwrap();
+        assert_eq!(multigraph.edge_multiplicity(&"A".to_string(), &"B".to_string()), 3);
+        assert_eq!(stats.duplicate_rows, 2);
+    }
+
+    #[test]
+    fn test_build_graph_from_reader() {
+        let input = io::Cursor::new("A,B\nB,C\nnope\n");
+        let (graph, stats) = build_graph_from_reader(input).unwrap();
+        assert_eq!(graph.edge_count(), 2);
+        assert_eq!(stats.skipped_lines, vec![3]);
+    }
+
+    #[test]
+    fn test_gzip_files_are_decompressed() {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(b"A,B\nB,C,2\nbroken\n").unwrap();
+        let path = write_temp_csv("edges.csv.gz", &encoder.finish().unwrap());
+        let (graph, stats) = build_graph_from_csv(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(graph.edge_count(), 2);
+        assert_eq!(graph.edge_weight(&"B".to_string(), &"C".to_string()), Some(2.0));
+        assert_eq!(stats.skipped_lines, vec![3]);
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::graph::Graph;
+
+
+impl<T: Eq + Hash + Clone> Graph<T> {
+    /// Pearson correlation between the degrees at either end of each edge,
+    /// using Newman's symmetric form so every undirected edge is visited once.
+    ///
+    /// Self-loops are skipped. Returns 0.0 when the correlation is undefined,
+    /// e.g. with no edges or when every edge joins nodes of equal degree.
+    pub fn degree_assortativity(&self) -> f64 {
+        let mut edges = 0.0;
+        let mut product_sum = 0.0;
+        let mut mean_sum = 0.0;
+        let mut square_sum = 0.0;
+
+        for (id, neighbors) in self.adjacency.iter().enumerate() {
+            let j = neighbors.len() as f64;
+            for &neighbor in neighbors.iter().filter(|&&neighbor| neighbor as usize > id) {
+                let k = self.neighbor_ids(neighbor).len() as f64;
+                edges += 1.0;
+                product_sum += j * k;
+                mean_sum += (j + k) / 2.0;
+                square_sum += (j * j + k * k) / 2.0;
+            }
+        }
+        if edges == 0.0 {
+            return 0.0;
+        }
+
+        let mean_squared = (mean_sum / edges).powi(2);
+        let denominator = square_sum / edges - mean_squared;
+        if denominator.abs() < 1e-12 {
+            return 0.0;
+        }
+        (product_sum / edges - mean_squared) / denominator
+    }
+
+
+    /// Rich-club coefficient: among the nodes with more than `k` neighbors
+    /// other than themselves, the fraction of the possible edges between them
+    /// that exist. Self-loops count neither toward the degree nor as links.
+    /// 0.0 when fewer than two nodes qualify.
+    pub fn rich_club_coefficient(&self, k: usize) -> f64 {
+        let rich: Vec<bool> = self
+            .adjacency
+            .iter()
+            .enumerate()
+            .map(|(id, neighbors)| neighbors.len() - usize::from(neighbors.contains(&(id as u32))) > k)
+            .collect();
+        let members = rich.iter().filter(|&&is_rich| is_rich).count();
+        if members < 2 {
+            return 0.0;
+        }
+
+        let mut links = 0;
+        for (id, neighbors) in self.adjacency.iter().enumerate().filter(|&(id, _)| rich[id]) {
+            links += neighbors.iter().filter(|&&neighbor| neighbor as usize > id && rich[neighbor as usize]).count();
+        }
+        links as f64 / (members * (members - 1) / 2) as f64
+    }
+
+
+    /// Mean degree of each node's neighbors, 0.0 for a node without any.
+    /// Degrees are as reported by `degree`, and a self-loop makes a node its
+    /// own neighbor.
+    pub fn average_neighbor_degree(&self) -> HashMap<T, f64> {
+        (0..self.node_count() as u32)
+            .map(|id| {
+                let neighbors = self.neighbor_ids(id);
+                let average = if neighbors.is_empty() {
+                    0.0
+                } else {
+                    let total: usize = neighbors.iter().map(|&neighbor| self.neighbor_ids(neighbor).len()).sum();
+                    total as f64 / neighbors.len() as f64
+                };
+                (self.label(id).clone(), average)
+            })
+            .collect()
+    }
+
+
+    /// Friendship paradox ratio: the mean over all nodes of
+    /// `average_neighbor_degree` divided by the mean `degree`. Above 1.0 a
+    /// typical node's neighbors have more neighbors than it does. 0.0 for an
+    /// empty or edgeless graph.
+    pub fn friendship_paradox_ratio(&self) -> f64 {
+        let degree_sum: usize = self.adjacency.iter().map(|neighbors| neighbors.len()).sum();
+        if degree_sum == 0 {
+            return 0.0;
+        }
+        // Both means divide by the node count, so it cancels out.
+        self.average_neighbor_degree().values().sum::<f64>() / degree_sum as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_degree_assortativity() {
+        // A star is perfectly disassortative: the hub only meets leaves.
+        let mut star = Graph::new();
+        for leaf in ["A", "B", "C", "D"] {
+            star.add_edge("Hub".to_string(), leaf.to_string());
+        }
+        assert!((star.degree_assortativity() + 1.0).abs() < 1e-12);
+
+        // Two disjoint edges plus a triangle: degrees always match at both ends.
+        let mut matched = Graph::new();
+        matched.add_edge("A".to_string(), "B".to_string());
+        matched.add_edge("X".to_string(), "Y".to_string());
+        matched.add_edge("Y".to_string(), "Z".to_string());
+        matched.add_edge("Z".to_string(), "X".to_string());
+        assert!((matched.degree_assortativity() - 1.0).abs() < 1e-12);
+
+        assert_eq!(Graph::<String>::new().degree_assortativity(), 0.0);
+    }
+
+    #[test]
+    fn test_average_neighbor_degree() {
+        // Hub with three leaves, one of which continues to a tail node.
+        let mut graph = Graph::new();
+        for leaf in ["A", "B", "C"] {
+            graph.add_edge("Hub".to_string(), leaf.to_string());
+        }
+        graph.add_edge("C".to_string(), "Tail".to_string());
+        graph.add_edge("Lone".to_string(), "Tail".to_string());
+        graph.remove_edge(&"Lone".to_string(), &"Tail".to_string());
+
+        let averages = graph.average_neighbor_degree();
+        assert_eq!(averages["A"], 3.0);
+        assert!((averages["Hub"] - 4.0 / 3.0).abs() < 1e-12);
+        assert_eq!(averages["C"], 2.0);
+        assert_eq!(averages["Tail"], 2.0);
+        assert_eq!(averages["Lone"], 0.0);
+    }
+
+    #[test]
+    fn test_rich_club_coefficient() {
+        // H1 and H2 have degree 4 and H3 degree 5; of their three pairs only
+        // H1-H2 and H2-H3 are linked.
+        let mut graph = Graph::new();
+        for (hub, leaves) in [("H1", ["a", "b", "c"]), ("H2", ["d", "e", "H3"]), ("H3", ["f", "g", "h"])] {
+            for leaf in leaves {
+                graph.add_edge(hub.to_string(), leaf.to_string());
+            }
+        }
+        graph.add_edge("H1".to_string(), "H2".to_string());
+        graph.add_edge("H3".to_string(), "i".to_string());
+
+        assert!((graph.rich_club_coefficient(3) - 2.0 / 3.0).abs() < 1e-12);
+        assert_eq!(graph.rich_club_coefficient(4), 0.0);
+        assert!(graph.rich_club_coefficient(0) > 0.0);
+        assert_eq!(Graph::<String>::new().rich_club_coefficient(0), 0.0);
+    }
+
+    #[test]
+    fn test_rich_club_ignores_self_loops() {
+        // With its loop X reaches degree 2 but has only one other neighbor.
+        let mut graph = Graph::new();
+        for (a, b) in [("A", "B"), ("B", "C"), ("C", "A"), ("A", "X"), ("X", "X")] {
+            graph.add_edge(a.to_string(), b.to_string());
+        }
+        assert_eq!(graph.rich_club_coefficient(1), 1.0);
+    }
+
+    #[test]
+    fn test_friendship_paradox_ratio() {
+        // Leaves see degree 3 and the hub sees degree 1: (3 * 3 + 1) / 4 over 6 / 4.
+        let mut star = Graph::new();
+        for leaf in ["A", "B", "C"] {
+            star.add_edge("Hub".to_string(), leaf.to_string());
+        }
+        assert!((star.friendship_paradox_ratio() - 5.0 / 3.0).abs() < 1e-12);
+
+        let mut triangle = Graph::new();
+        for (a, b) in [("A", "B"), ("B", "C"), ("C", "A")] {
+            triangle.add_edge(a.to_string(), b.to_string());
+        }
+        assert!((triangle.friendship_paradox_ratio() - 1.0).abs() < 1e-12);
+
+        triangle.remove_node(&"A".to_string());
+        triangle.remove_edge(&"B".to_string(), &"C".to_string());
+        assert_eq!(triangle.friendship_paradox_ratio(), 0.0);
+        assert_eq!(Graph::<String>::new().friendship_paradox_ratio(), 0.0);
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use rayon::prelude::*;
+
+use crate::graph::Graph;
+
+
+/// Brandes passes run per batch; bounds the memory held by pending
+/// dependency vectors to `BATCH_SIZE * n`.
+const BATCH_SIZE: usize = 64;
+
+
+impl<T: Eq + Hash + Clone + Send + Sync> Graph<T> {
+    /// `degree_distribution` computed with per-thread partial maps that are
+    /// merged at the end.
+    pub fn degree_distribution_parallel(&self) -> HashMap<usize, usize> {
+        self.adjacency
+            .par_iter()
+            .fold(HashMap::new, |mut distribution, neighbors| {
+                *distribution.entry(neighbors.len()).or_insert(0) += 1;
+                distribution
+            })
+            .reduce(HashMap::new, |mut merged, partial| {
+                for (degree, count) in partial {
+                    *merged.entry(degree).or_insert(0) += count;
+                }
+                merged
+            })
+    }
+
+
+    /// `betweenness_centrality` with the Brandes passes spread across threads.
+    /// Dependencies are still summed in source order, so the scores match the
+    /// serial version exactly.
+    pub fn betweenness_centrality_parallel(&self) -> HashMap<T, f64> {
+        let n = self.node_count();
+        let mut centrality = vec![0.0; n];
+        for batch_start in (0..n).step_by(BATCH_SIZE) {
+            let batch_end = (batch_start + BATCH_SIZE).min(n);
+            let batch: Vec<Vec<f64>> = (batch_start as u32..batch_end as u32)
+                .into_par_iter()
+                .map(|source| self.dependencies_from(source))
+                .collect();
+            for dependencies in batch {
+                for (score, dependency) in centrality.iter_mut().zip(dependencies) {
+                    *score += dependency;
+                }
+            }
+        }
+
+        self.nodes.iter().cloned().zip(centrality.into_iter().map(|score| score / 2.0)).collect()
+    }
+
+
+    /// `closeness_centrality` with one BFS per node, run across threads.
+    pub fn closeness_centrality_parallel(&self) -> HashMap<T, f64> {
+        (0..self.node_count() as u32)
+            .into_par_iter()
+            .map(|id| (self.label(id).clone(), self.closeness_of(id)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_graph() -> Graph {
+        let mut graph = Graph::new();
+        for i in 0..150 {
+            graph.add_edge(format!("N{}", i), format!("N{}", (i * 7 + 3) % 150));
+            graph.add_edge(format!("N{}", i), format!("N{}", (i + 1) % 150));
+        }
+        graph.add_edge("Lonely".to_string(), "Lonely".to_string());
+        graph
+    }
+
+    #[test]
+    fn test_parallel_matches_serial() {
+        let graph = sample_graph();
+        assert_eq!(graph.degree_distribution_parallel(), graph.degree_distribution());
+        assert_eq!(graph.betweenness_centrality_parallel(), graph.betweenness_centrality());
+        assert_eq!(graph.closeness_centrality_parallel(), graph.closeness_centrality());
+        assert!(Graph::<String>::new().betweenness_centrality_parallel().is_empty());
+    }
+}
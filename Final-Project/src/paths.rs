@@ -0,0 +1,494 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::fmt;
+use std::hash::Hash;
+
+use crate::graph::Graph;
+use crate::rng::Rng;
+
+
+/// Returned by `dijkstra` when the graph holds an edge of negative weight;
+/// carries the most negative weight found.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NegativeWeightError {
+    pub weight: f64,
+}
+
+impl fmt::Display for NegativeWeightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "negative edge weight {} is not supported by Dijkstra's algorithm", self.weight)
+    }
+}
+
+impl std::error::Error for NegativeWeightError {}
+
+
+/// Frontier entry for Dijkstra, ordered so the max-heap pops the smallest
+/// distance first.
+struct Frontier(f64, u32);
+
+impl PartialEq for Frontier {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Frontier {}
+
+impl PartialOrd for Frontier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Frontier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.total_cmp(&self.0).then_with(|| other.1.cmp(&self.1))
+    }
+}
+
+
+impl<T: Eq + Hash + Clone> Graph<T> {
+    /// Hop distance from `source` to every node, `None` where unreachable.
+    pub(crate) fn bfs_distances(&self, source: u32) -> Vec<Option<usize>> {
+        let mut distances = vec![None; self.node_count()];
+        distances[source as usize] = Some(0);
+        let mut queue = VecDeque::new();
+        queue.push_back(source);
+
+        while let Some(current) = queue.pop_front() {
+            let distance = distances[current as usize].unwrap();
+            for &neighbor in self.neighbor_ids(current) {
+                if distances[neighbor as usize].is_none() {
+                    distances[neighbor as usize] = Some(distance + 1);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        distances
+    }
+
+
+    /// Number of hops on a shortest path between `from` and `to`, or `None`
+    /// when either node is unknown or they lie in different components.
+    pub fn shortest_path_length(&self, from: &T, to: &T) -> Option<usize> {
+        let (from, to) = (self.id(from)?, self.id(to)?);
+
+        let mut distances = vec![None; self.node_count()];
+        distances[from as usize] = Some(0);
+        let mut queue = VecDeque::new();
+        queue.push_back(from);
+
+        while let Some(current) = queue.pop_front() {
+            let distance = distances[current as usize].unwrap();
+            if current == to {
+                return Some(distance);
+            }
+            for &neighbor in self.neighbor_ids(current) {
+                if distances[neighbor as usize].is_none() {
+                    distances[neighbor as usize] = Some(distance + 1);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        None
+    }
+
+
+    /// Whether `to` can be reached from `from`, stopping the BFS as soon as
+    /// it is found. True for `from == to` when the node exists, false when
+    /// either node is unknown.
+    pub fn path_exists(&self, from: &T, to: &T) -> bool {
+        let (Some(from), Some(to)) = (self.id(from), self.id(to)) else {
+            return false;
+        };
+
+        let mut visited = vec![false; self.node_count()];
+        visited[from as usize] = true;
+        let mut queue = VecDeque::new();
+        queue.push_back(from);
+        while let Some(current) = queue.pop_front() {
+            if current == to {
+                return true;
+            }
+            for &neighbor in self.neighbor_ids(current) {
+                if !visited[neighbor as usize] {
+                    visited[neighbor as usize] = true;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        false
+    }
+
+
+    fn eccentricity_of(&self, id: u32) -> Option<usize> {
+        self.bfs_distances(id).into_iter().try_fold(0, |max, distance| Some(max.max(distance?)))
+    }
+
+
+    /// Greatest distance from `node` to any other node, or `None` when the
+    /// node is unknown or some node cannot be reached from it.
+    pub fn eccentricity(&self, node: &T) -> Option<usize> {
+        self.eccentricity_of(self.id(node)?)
+    }
+
+
+    fn eccentricities(&self) -> Option<Vec<usize>> {
+        (0..self.node_count() as u32).map(|id| self.eccentricity_of(id)).collect()
+    }
+
+
+    /// Longest shortest path; `None` for an empty or disconnected graph.
+    pub fn diameter(&self) -> Option<usize> {
+        self.eccentricities()?.into_iter().max()
+    }
+
+
+    /// Smallest eccentricity; `None` for an empty or disconnected graph.
+    pub fn radius(&self) -> Option<usize> {
+        self.eccentricities()?.into_iter().min()
+    }
+
+
+    /// Mean shortest-path distance over all pairs of distinct nodes that can
+    /// reach each other. Pairs in different components are skipped rather than
+    /// treated as infinitely far apart, so a disconnected graph averages over
+    /// its components together; `None` when no such pair exists.
+    pub fn average_path_length(&self) -> Option<f64> {
+        let mut total_distance = 0;
+        let mut pairs = 0;
+        for id in 0..self.node_count() as u32 {
+            for distance in self.bfs_distances(id).into_iter().flatten() {
+                if distance > 0 {
+                    total_distance += distance;
+                    pairs += 1;
+                }
+            }
+        }
+
+        if pairs == 0 {
+            return None;
+        }
+        Some(total_distance as f64 / pairs as f64)
+    }
+
+
+    /// Histogram of shortest-path lengths: how many unordered pairs of
+    /// distinct nodes lie at each distance. Pairs in different components are
+    /// left out. Runs a BFS from every node, so it takes O(n·(n + m)) time.
+    pub fn path_length_distribution(&self) -> HashMap<usize, usize> {
+        let mut distribution = HashMap::new();
+        for id in 0..self.node_count() as u32 {
+            let distances = self.bfs_distances(id);
+            for distance in distances.into_iter().skip(id as usize + 1).flatten() {
+                *distribution.entry(distance).or_insert(0) += 1;
+            }
+        }
+        distribution
+    }
+
+
+    /// Wiener index: the sum of shortest-path lengths over all unordered pairs
+    /// of distinct nodes. On a disconnected graph pairs in different
+    /// components are skipped, so it is the sum of the components' indices.
+    pub fn wiener_index(&self) -> usize {
+        self.path_length_distribution().into_iter().map(|(distance, pairs)| distance * pairs).sum()
+    }
+
+
+    /// Subgraph induced by every node within `radius` hops of `node`, the node
+    /// itself included. An unknown node yields an empty graph.
+    pub fn ego_network(&self, node: &T, radius: usize) -> Graph<T> {
+        let Some(center) = self.id(node) else {
+            return Graph::new();
+        };
+
+        let mut visited = vec![false; self.node_count()];
+        visited[center as usize] = true;
+        let mut members = vec![center];
+        let mut queue = VecDeque::new();
+        queue.push_back((center, 0));
+        while let Some((current, distance)) = queue.pop_front() {
+            if distance == radius {
+                continue;
+            }
+            for &neighbor in self.neighbor_ids(current) {
+                if !visited[neighbor as usize] {
+                    visited[neighbor as usize] = true;
+                    members.push(neighbor);
+                    queue.push_back((neighbor, distance + 1));
+                }
+            }
+        }
+
+        self.subgraph_of_ids(&members)
+    }
+
+
+    /// Weighted distance from `from` to every node it can reach, itself at
+    /// 0.0; unreachable nodes are left out of the map, and an unknown `from`
+    /// gives an empty map. Unweighted edges weigh 1.0.
+    ///
+    /// Fails if any edge in the graph has a negative weight.
+    pub fn dijkstra(&self, from: &T) -> Result<HashMap<T, f64>, NegativeWeightError> {
+        if let Some(weight) = self.weights.values().copied().filter(|&weight| weight < 0.0).min_by(f64::total_cmp) {
+            return Err(NegativeWeightError { weight });
+        }
+        let Some(source) = self.id(from) else {
+            return Ok(HashMap::new());
+        };
+
+        let mut distances: Vec<Option<f64>> = vec![None; self.node_count()];
+        let mut settled = vec![false; self.node_count()];
+        distances[source as usize] = Some(0.0);
+        let mut frontier = BinaryHeap::new();
+        frontier.push(Frontier(0.0, source));
+
+        while let Some(Frontier(distance, current)) = frontier.pop() {
+            if settled[current as usize] {
+                continue;
+            }
+            settled[current as usize] = true;
+            for &neighbor in self.neighbor_ids(current) {
+                let candidate = distance + self.weight_of(current, neighbor);
+                if distances[neighbor as usize].is_none_or(|known| candidate < known) {
+                    distances[neighbor as usize] = Some(candidate);
+                    frontier.push(Frontier(candidate, neighbor));
+                }
+            }
+        }
+
+        Ok(distances
+            .into_iter()
+            .enumerate()
+            .filter_map(|(id, distance)| Some((self.label(id as u32).clone(), distance?)))
+            .collect())
+    }
+}
+
+
+impl<T: Eq + Hash + Clone + Ord> Graph<T> {
+    /// Nodes on a shortest path from `from` to `to`, both ends included.
+    ///
+    /// Neighbors are explored in sorted order, so ties between equally short
+    /// paths are always broken the same way.
+    pub fn shortest_path(&self, from: &T, to: &T) -> Option<Vec<T>> {
+        let (from, to) = (self.id(from)?, self.id(to)?);
+
+        let mut predecessors: Vec<Option<u32>> = vec![None; self.node_count()];
+        let mut visited = vec![false; self.node_count()];
+        visited[from as usize] = true;
+        let mut queue = VecDeque::new();
+        queue.push_back(from);
+
+        while let Some(current) = queue.pop_front() {
+            if current == to {
+                let mut path = vec![self.label(current).clone()];
+                let mut step = current;
+                while let Some(previous) = predecessors[step as usize] {
+                    path.push(self.label(previous).clone());
+                    step = previous;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for neighbor in self.sorted_neighbor_ids(current) {
+                if !visited[neighbor as usize] {
+                    visited[neighbor as usize] = true;
+                    predecessors[neighbor as usize] = Some(current);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        None
+    }
+
+
+    /// Uniform random walk of up to `length` steps from `start`, returned with
+    /// `start` first. The walk stops early at a node without neighbors, and an
+    /// unknown `start` gives an empty walk. The same seed always gives the
+    /// same walk.
+    pub fn random_walk(&self, start: &T, length: usize, seed: u64) -> Vec<T> {
+        let Some(mut current) = self.id(start) else {
+            return Vec::new();
+        };
+
+        let mut rng = Rng::new(seed);
+        let mut walk = vec![self.label(current).clone()];
+        for _ in 0..length {
+            let neighbors = self.sorted_neighbor_ids(current);
+            if neighbors.is_empty() {
+                break;
+            }
+            current = neighbors[rng.below(neighbors.len())];
+            walk.push(self.label(current).clone());
+        }
+        walk
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn path_graph() -> Graph {
+        let mut graph = Graph::new();
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("B".to_string(), "C".to_string());
+        graph.add_edge("C".to_string(), "D".to_string());
+        graph.add_edge("X".to_string(), "Y".to_string());
+        graph
+    }
+
+    #[test]
+    fn test_shortest_path_length() {
+        let graph = path_graph();
+        let a = "A".to_string();
+
+        assert_eq!(graph.shortest_path_length(&a, &a), Some(0));
+        assert_eq!(graph.shortest_path_length(&a, &"D".to_string()), Some(3));
+        assert_eq!(graph.shortest_path_length(&a, &"X".to_string()), None);
+        assert_eq!(graph.shortest_path_length(&a, &"Z".to_string()), None);
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let mut graph = path_graph();
+        graph.add_edge("A".to_string(), "E".to_string());
+        graph.add_edge("E".to_string(), "C".to_string());
+        let a = "A".to_string();
+
+        assert_eq!(graph.shortest_path(&a, &a), Some(vec![a.clone()]));
+        let expected: Vec<String> = ["A", "B", "C", "D"].iter().map(|s| s.to_string()).collect();
+        for _ in 0..10 {
+            assert_eq!(graph.shortest_path(&a, &"D".to_string()), Some(expected.clone()));
+        }
+        assert_eq!(graph.shortest_path(&a, &"Y".to_string()), None);
+    }
+
+    #[test]
+    fn test_eccentricity_diameter_radius() {
+        let mut graph = Graph::new();
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("B".to_string(), "C".to_string());
+        graph.add_edge("C".to_string(), "D".to_string());
+
+        assert_eq!(graph.eccentricity(&"A".to_string()), Some(3));
+        assert_eq!(graph.eccentricity(&"B".to_string()), Some(2));
+        assert_eq!(graph.eccentricity(&"Z".to_string()), None);
+        assert_eq!(graph.diameter(), Some(3));
+        assert_eq!(graph.radius(), Some(2));
+
+        let disconnected = path_graph();
+        assert_eq!(disconnected.eccentricity(&"A".to_string()), None);
+        assert_eq!(disconnected.diameter(), None);
+        assert_eq!(disconnected.radius(), None);
+        assert_eq!(Graph::<String>::new().diameter(), None);
+    }
+
+    #[test]
+    fn test_ego_network() {
+        let mut graph = path_graph();
+        graph.add_edge("A".to_string(), "C".to_string());
+
+        let ego = graph.ego_network(&"B".to_string(), 1);
+        assert_eq!(ego.node_count(), 3);
+        // A-C joins two neighbors of B, so it stays.
+        assert_eq!(ego.edge_count(), 3);
+        assert!(!ego.contains_node(&"D".to_string()));
+
+        assert_eq!(graph.ego_network(&"B".to_string(), 0).node_count(), 1);
+        assert_eq!(graph.ego_network(&"A".to_string(), 5).node_count(), 4);
+        assert_eq!(graph.ego_network(&"Z".to_string(), 1).node_count(), 0);
+    }
+
+    #[test]
+    fn test_random_walk() {
+        let mut graph = path_graph();
+        graph.add_edge("A".to_string(), "C".to_string());
+        graph.add_edge("Lonely".to_string(), "Z".to_string());
+        graph.remove_node(&"Z".to_string());
+
+        let walk = graph.random_walk(&"A".to_string(), 20, 42);
+        assert_eq!(walk.len(), 21);
+        assert_eq!(walk[0], "A");
+        assert!(walk.windows(2).all(|step| graph.has_edge(&step[0], &step[1])));
+        assert_eq!(graph.random_walk(&"A".to_string(), 20, 42), walk);
+
+        assert_eq!(graph.random_walk(&"Lonely".to_string(), 5, 1), vec!["Lonely"]);
+        assert!(graph.random_walk(&"Nobody".to_string(), 5, 1).is_empty());
+    }
+
+    #[test]
+    fn test_average_path_length() {
+        // A-B-C-D contributes distances 1,1,1,2,2,3 and X-Y contributes 1.
+        let graph = path_graph();
+        assert!((graph.average_path_length().unwrap() - 11.0 / 7.0).abs() < 1e-12);
+
+        let mut single = Graph::new();
+        single.add_edge("A".to_string(), "A".to_string());
+        assert_eq!(single.average_path_length(), None);
+        assert_eq!(Graph::<String>::new().average_path_length(), None);
+    }
+
+    #[test]
+    fn test_path_length_distribution() {
+        // A-B-C-D has three pairs at 1, two at 2 and one at 3; X-Y adds one more at 1.
+        let mut graph = path_graph();
+        graph.add_edge("D".to_string(), "D".to_string());
+        assert_eq!(graph.path_length_distribution(), HashMap::from([(1, 4), (2, 2), (3, 1)]));
+        assert!(Graph::<String>::new().path_length_distribution().is_empty());
+    }
+
+    #[test]
+    fn test_wiener_index() {
+        // A path on n nodes has Wiener index (n^3 - n) / 6.
+        let mut path = Graph::new();
+        for i in 0..5 {
+            path.add_edge(i.to_string(), (i + 1).to_string());
+        }
+        assert_eq!(path.wiener_index(), (6 * 6 * 6 - 6) / 6);
+
+        // Components add up: 10 for A-B-C-D and 1 for X-Y.
+        assert_eq!(path_graph().wiener_index(), 11);
+        assert_eq!(Graph::<String>::new().wiener_index(), 0);
+    }
+
+    #[test]
+    fn test_path_exists() {
+        let graph = path_graph();
+        let (a, d, x, q) = ("A".to_string(), "D".to_string(), "X".to_string(), "Q".to_string());
+        assert!(graph.path_exists(&a, &d));
+        assert!(graph.path_exists(&d, &a));
+        assert!(graph.path_exists(&x, &x));
+        assert!(!graph.path_exists(&a, &x));
+        assert!(!graph.path_exists(&a, &q));
+        assert!(!graph.path_exists(&q, &q));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let mut graph = Graph::new();
+        graph.add_weighted_edge("A".to_string(), "B".to_string(), 4.0);
+        graph.add_weighted_edge("A".to_string(), "C".to_string(), 1.0);
+        graph.add_weighted_edge("C".to_string(), "B".to_string(), 1.5);
+        graph.add_edge("B".to_string(), "D".to_string());
+        graph.add_edge("X".to_string(), "Y".to_string());
+
+        let distances = graph.dijkstra(&"A".to_string()).unwrap();
+        assert_eq!(distances.len(), 4);
+        assert_eq!(distances["A"], 0.0);
+        assert_eq!(distances["B"], 2.5);
+        assert_eq!(distances["D"], 3.5);
+        assert!(!distances.contains_key("X"));
+        assert!(graph.dijkstra(&"Q".to_string()).unwrap().is_empty());
+
+        graph.add_weighted_edge("X".to_string(), "Y".to_string(), -3.0);
+        assert_eq!(graph.dijkstra(&"A".to_string()), Err(NegativeWeightError { weight: -3.0 }));
+    }
+}
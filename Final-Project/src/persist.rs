@@ -0,0 +1,344 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::graph::{weight_key, Graph};
+
+
+/// On-disk shape of a `Graph`. Sorted containers keep the output stable, and
+/// weights are listed separately because JSON object keys must be strings.
+#[derive(Serialize, Deserialize)]
+#[serde(bound(deserialize = "T: Deserialize<'de> + Ord"))]
+struct GraphRepr<T> {
+    adjacency: BTreeMap<T, BTreeSet<T>>,
+    #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
+    weights: Vec<(T, T, f64)>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    multigraph: bool,
+    /// Edges added more than once, with their total multiplicity.
+    #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
+    multiplicities: Vec<(T, T, usize)>,
+    #[serde(default = "BTreeMap::new", skip_serializing_if = "BTreeMap::is_empty")]
+    attributes: BTreeMap<T, BTreeMap<String, String>>,
+}
+
+/// Endpoints of the edge between two ids, smaller label first.
+fn sorted_ends<T: Eq + Hash + Clone + Ord>(graph: &Graph<T>, id1: u32, id2: u32) -> (&T, &T) {
+    let (node1, node2) = (graph.label(id1), graph.label(id2));
+    (node1.min(node2), node1.max(node2))
+}
+
+impl<T: Serialize + Ord + Hash + Clone> Serialize for Graph<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let adjacency = self
+            .nodes
+            .iter()
+            .zip(&self.adjacency)
+            .map(|(node, neighbors)| (node, neighbors.iter().map(|&neighbor| self.label(neighbor)).collect()))
+            .collect();
+        let mut weights: Vec<(&T, &T, f64)> = self
+            .weights
+            .iter()
+            .map(|(&(id1, id2), weight)| {
+                let (node1, node2) = sorted_ends(self, id1, id2);
+                (node1, node2, *weight)
+            })
+            .collect();
+        weights.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+        let mut multiplicities: Vec<(&T, &T, usize)> = self
+            .multiplicities
+            .iter()
+            .map(|(&(id1, id2), extra)| {
+                let (node1, node2) = sorted_ends(self, id1, id2);
+                (node1, node2, extra + 1)
+            })
+            .collect();
+        multiplicities.sort();
+        let attributes = self
+            .attributes
+            .iter()
+            .map(|(&id, attributes)| (self.label(id), attributes.clone().into_iter().collect()))
+            .collect();
+
+        GraphRepr {
+            adjacency,
+            weights,
+            multigraph: self.multigraph,
+            multiplicities,
+            attributes,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Ord + Hash + Clone> Deserialize<'de> for Graph<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = GraphRepr::<T>::deserialize(deserializer)?;
+        let mut graph = Graph::new();
+        for (node, neighbors) in repr.adjacency {
+            graph.intern(node.clone());
+            for neighbor in neighbors {
+                graph.add_edge(node.clone(), neighbor);
+            }
+        }
+        for (node1, node2, weight) in repr.weights {
+            if let (Some(id1), Some(id2)) = (graph.id(&node1), graph.id(&node2)) {
+                if graph.neighbor_ids(id1).contains(&id2) {
+                    graph.weights.insert(weight_key(id1, id2), weight);
+                }
+            }
+        }
+        graph.multigraph = repr.multigraph;
+        for (node1, node2, multiplicity) in repr.multiplicities {
+            if let (true, Some(id1), Some(id2)) = (graph.multigraph, graph.id(&node1), graph.id(&node2)) {
+                if graph.neighbor_ids(id1).contains(&id2) && multiplicity > 1 {
+                    graph.multiplicities.insert(weight_key(id1, id2), multiplicity - 1);
+                }
+            }
+        }
+        for (node, attributes) in repr.attributes {
+            for (key, value) in attributes {
+                graph.set_node_attribute(&node, key, value);
+            }
+        }
+        Ok(graph)
+    }
+}
+
+
+impl<T: Serialize + Ord + Hash + Clone> Graph<T> {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("a graph always serializes to JSON")
+    }
+}
+
+
+impl<T: DeserializeOwned + Ord + Hash + Clone> Graph<T> {
+    /// Parses a graph written by `to_json`. Edges listed in only one direction
+    /// are made symmetric.
+    pub fn from_json(json: &str) -> Result<Graph<T>, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+
+/// Leading bytes of a `save_binary` file: a tag and the format version.
+const BINARY_MAGIC: &[u8; 5] = b"DDGB\x01";
+
+const EDGE_HAS_WEIGHT: u8 = 1;
+const EDGE_HAS_COPIES: u8 = 2;
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn write_u32(writer: &mut impl Write, value: usize) -> io::Result<()> {
+    let value = u32::try_from(value).map_err(|_| invalid_data("count does not fit in 32 bits"))?;
+    writer.write_all(&value.to_le_bytes())
+}
+
+fn write_str(writer: &mut impl Write, text: &str) -> io::Result<()> {
+    write_u32(writer, text.len())?;
+    writer.write_all(text.as_bytes())
+}
+
+fn read_array<const N: usize>(reader: &mut impl Read) -> io::Result<[u8; N]> {
+    let mut bytes = [0; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    read_array(reader).map(u32::from_le_bytes)
+}
+
+fn read_str(reader: &mut impl Read) -> io::Result<String> {
+    let len = read_u32(reader)? as usize;
+    let mut bytes = Vec::new();
+    reader.take(len as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != len {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
+    String::from_utf8(bytes).map_err(|_| invalid_data("node label is not valid UTF-8"))
+}
+
+
+impl Graph<String> {
+    /// Writes the graph in a compact little-endian binary format: the magic
+    /// bytes, a multigraph flag, the length-prefixed labels in id order,
+    /// then each edge once as two ids with its optional weight and copy count,
+    /// and finally the node attributes. Read it back with `load_binary`.
+    pub fn save_binary(&self, path: &str) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(BINARY_MAGIC)?;
+        writer.write_all(&[u8::from(self.multigraph)])?;
+
+        write_u32(&mut writer, self.node_count())?;
+        for node in &self.nodes {
+            write_str(&mut writer, node)?;
+        }
+
+        write_u32(&mut writer, self.edge_count())?;
+        for (id, neighbors) in self.adjacency.iter().enumerate() {
+            let id = id as u32;
+            let mut later: Vec<u32> = neighbors.iter().copied().filter(|&neighbor| neighbor >= id).collect();
+            later.sort_unstable();
+            for neighbor in later {
+                let weight = self.weights.get(&weight_key(id, neighbor));
+                let copies = self.multiplicities.get(&weight_key(id, neighbor));
+                let mut flags = 0;
+                if weight.is_some() {
+                    flags |= EDGE_HAS_WEIGHT;
+                }
+                if copies.is_some() {
+                    flags |= EDGE_HAS_COPIES;
+                }
+                writer.write_all(&id.to_le_bytes())?;
+                writer.write_all(&neighbor.to_le_bytes())?;
+                writer.write_all(&[flags])?;
+                if let Some(weight) = weight {
+                    writer.write_all(&weight.to_le_bytes())?;
+                }
+                if let Some(&copies) = copies {
+                    write_u32(&mut writer, copies)?;
+                }
+            }
+        }
+
+        let mut attributed: Vec<(&u32, &HashMap<String, String>)> = self.attributes.iter().collect();
+        attributed.sort_unstable_by_key(|&(&id, _)| id);
+        write_u32(&mut writer, attributed.len())?;
+        for (&id, attributes) in attributed {
+            let sorted: BTreeMap<&String, &String> = attributes.iter().collect();
+            write_u32(&mut writer, id as usize)?;
+            write_u32(&mut writer, sorted.len())?;
+            for (key, value) in sorted {
+                write_str(&mut writer, key)?;
+                write_str(&mut writer, value)?;
+            }
+        }
+        writer.flush()
+    }
+
+
+    /// Reads a graph written by `save_binary`. Fails with `InvalidData` on a
+    /// file of another format or with out-of-range ids, and with
+    /// `UnexpectedEof` on a truncated one.
+    pub fn load_binary(path: &str) -> io::Result<Graph> {
+        let mut reader = BufReader::new(File::open(path)?);
+        if &read_array::<5>(&mut reader)? != BINARY_MAGIC {
+            return Err(invalid_data("not a degree_distribution binary graph"));
+        }
+        let mut graph = Graph { multigraph: read_array::<1>(&mut reader)?[0] != 0, ..Graph::new() };
+
+        let node_count = read_u32(&mut reader)?;
+        for _ in 0..node_count {
+            let node = read_str(&mut reader)?;
+            if graph.contains_node(&node) {
+                return Err(invalid_data("node label is repeated"));
+            }
+            graph.intern(node);
+        }
+        let check = |id: u32| if id < node_count { Ok(id) } else { Err(invalid_data("node id out of range")) };
+
+        for _ in 0..read_u32(&mut reader)? {
+            let (id1, id2) = (check(read_u32(&mut reader)?)?, check(read_u32(&mut reader)?)?);
+            let flags = read_array::<1>(&mut reader)?[0];
+            graph.adjacency[id1 as usize].insert(id2);
+            graph.adjacency[id2 as usize].insert(id1);
+            if flags & EDGE_HAS_WEIGHT != 0 {
+                graph.weights.insert(weight_key(id1, id2), f64::from_le_bytes(read_array(&mut reader)?));
+            }
+            if flags & EDGE_HAS_COPIES != 0 {
+                graph.multiplicities.insert(weight_key(id1, id2), read_u32(&mut reader)? as usize);
+            }
+        }
+
+        for _ in 0..read_u32(&mut reader)? {
+            let id = check(read_u32(&mut reader)?)?;
+            let mut attributes = HashMap::new();
+            for _ in 0..read_u32(&mut reader)? {
+                attributes.insert(read_str(&mut reader)?, read_str(&mut reader)?);
+            }
+            graph.attributes.insert(id, attributes);
+        }
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_round_trip() {
+        let mut graph = Graph::new();
+        graph.add_weighted_edge("A".to_string(), "B".to_string(), 2.5);
+        graph.add_edge("B".to_string(), "C".to_string());
+        graph.add_edge("D".to_string(), "D".to_string());
+        graph.add_edge("E".to_string(), "F".to_string());
+        graph.remove_edge(&"E".to_string(), &"F".to_string());
+        graph.set_node_attribute(&"A".to_string(), "country".to_string(), "NZ".to_string());
+
+        let json = graph.to_json();
+        let restored = Graph::from_json(&json).unwrap();
+
+        assert!(restored == graph);
+        assert_eq!(restored.edge_weight(&"A".to_string(), &"B".to_string()), Some(2.5));
+        assert!(restored.contains_node(&"E".to_string()));
+        assert_eq!(restored.get_node_attribute(&"A".to_string(), "country"), Some(&"NZ".to_string()));
+        assert_eq!(restored.to_json(), json);
+    }
+
+    #[test]
+    fn test_multigraph_round_trip() {
+        let mut graph = Graph::new_multigraph();
+        graph.add_edge("B".to_string(), "A".to_string());
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("B".to_string(), "C".to_string());
+
+        let restored = Graph::<String>::from_json(&graph.to_json()).unwrap();
+        assert!(restored.is_multigraph());
+        assert_eq!(restored.edge_multiplicity(&"A".to_string(), &"B".to_string()), 2);
+        assert_eq!(restored.edge_multiplicity(&"C".to_string(), &"B".to_string()), 1);
+        assert!(!Graph::<String>::from_json(&Graph::<String>::new().to_json()).unwrap().is_multigraph());
+    }
+
+    #[test]
+    fn test_from_json_rejects_garbage() {
+        assert!(Graph::<String>::from_json("{\"adjacency\": 3}").is_err());
+    }
+
+    #[test]
+    fn test_binary_round_trip() {
+        let mut graph = Graph::new_multigraph();
+        graph.add_weighted_edge("A".to_string(), "Zoë".to_string(), 2.5);
+        graph.add_edge("B".to_string(), "A".to_string());
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("D".to_string(), "D".to_string());
+        graph.add_edge("E".to_string(), "F".to_string());
+        graph.remove_edge(&"E".to_string(), &"F".to_string());
+        graph.set_node_attribute(&"A".to_string(), "country".to_string(), "NZ".to_string());
+
+        let path = std::env::temp_dir().join(format!("degree_distribution_{}_graph.bin", std::process::id()));
+        let path = path.to_str().unwrap();
+        graph.save_binary(path).unwrap();
+        let restored = Graph::load_binary(path);
+        std::fs::write(path, b"DDGB\x01\x00\x05\x00").unwrap();
+        let truncated = Graph::load_binary(path);
+        std::fs::write(path, graph.to_json()).unwrap();
+        let foreign = Graph::load_binary(path);
+        std::fs::remove_file(path).unwrap();
+
+        let restored = restored.unwrap();
+        assert!(restored == graph);
+        assert_eq!(restored.to_json(), graph.to_json());
+        assert_eq!(restored.edge_multiplicity(&"A".to_string(), &"B".to_string()), 2);
+        assert_eq!(truncated.err().map(|e| e.kind()), Some(io::ErrorKind::UnexpectedEof));
+        assert_eq!(foreign.err().map(|e| e.kind()), Some(io::ErrorKind::InvalidData));
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+
+
+pub fn evaluate_power_law(distribution: &HashMap<usize, usize>) -> f64 {
+    evaluate_power_law_with(distribution, 2.5, 1)
+}
+
+
+/// Maximum-likelihood estimate of the power-law exponent (Clauset et al.):
+/// `alpha = 1 + n / sum(ln(k / k_min))`, where `k_min` is the smallest
+/// positive degree and `n` counts the nodes with degree at least `k_min`.
+///
+/// Degree-0 nodes are ignored. Returns `None` when no node has a positive
+/// degree or every positive degree equals `k_min`, where the estimate would
+/// be NaN or infinite.
+pub fn fit_power_law_exponent(distribution: &HashMap<usize, usize>) -> Option<f64> {
+    let smallest_degree = distribution
+        .iter()
+        .filter(|&(&degree, &count)| degree > 0 && count > 0)
+        .map(|(&degree, _)| degree)
+        .min();
+    let k_min = smallest_degree? as f64;
+
+    let mut nodes = 0.0;
+    let mut log_sum = 0.0;
+    for (&degree, &count) in distribution {
+        if degree > 0 {
+            nodes += count as f64;
+            log_sum += count as f64 * (degree as f64 / k_min).ln();
+        }
+    }
+    if log_sum == 0.0 {
+        return None;
+    }
+    Some(1.0 + nodes / log_sum)
+}
+
+
+/// Fits the exponent with `fit_power_law_exponent` and scores the
+/// distribution against it. Returns `(alpha, score)`, or `None` when no
+/// exponent can be fitted.
+pub fn evaluate_fitted_power_law(distribution: &HashMap<usize, usize>) -> Option<(f64, f64)> {
+    let alpha = fit_power_law_exponent(distribution)?;
+    Some((alpha, evaluate_power_law_with(distribution, alpha, 1)))
+}
+
+
+/// Kolmogorov-Smirnov statistic `D`: the largest gap between the empirical
+/// degree CDF and that of a discrete power law with exponent `alpha`,
+/// truncated to the observed range from the smallest positive degree up to
+/// the largest degree. Degree-0 nodes are ignored; NaN when none remain.
+pub fn ks_statistic(distribution: &HashMap<usize, usize>, alpha: f64) -> f64 {
+    let mut observed: Vec<(usize, usize)> = distribution
+        .iter()
+        .map(|(&degree, &count)| (degree, count))
+        .filter(|&(degree, count)| degree > 0 && count > 0)
+        .collect();
+    observed.sort_unstable();
+    let (k_min, k_max) = match (observed.first(), observed.last()) {
+        (Some(&(k_min, _)), Some(&(k_max, _))) => (k_min, k_max),
+        _ => return f64::NAN,
+    };
+
+    let total_nodes: usize = observed.iter().map(|&(_, count)| count).sum();
+    let normalization: f64 = (k_min..=k_max).map(|degree| (degree as f64).powf(-alpha)).sum();
+
+    let mut counts = observed.into_iter().peekable();
+    let mut empirical_nodes = 0;
+    let mut theoretical_cdf = 0.0;
+    let mut max_gap: f64 = 0.0;
+    for degree in k_min..=k_max {
+        if let Some((_, count)) = counts.next_if(|&(observed_degree, _)| observed_degree == degree) {
+            empirical_nodes += count;
+        }
+        theoretical_cdf += (degree as f64).powf(-alpha) / normalization;
+        let empirical_cdf = empirical_nodes as f64 / total_nodes as f64;
+        max_gap = max_gap.max((empirical_cdf - theoretical_cdf).abs());
+    }
+    max_gap
+}
+
+
+/// `1 / (1 + MSE)` between the observed degree probabilities and a
+/// discrete power law with exponent `alpha`, both restricted to degrees of
+/// at least `k_min` (and never degree 0). Scores 0.0 when no degree is left.
+pub fn evaluate_power_law_with(distribution: &HashMap<usize, usize>, alpha: f64, k_min: usize) -> f64 {
+    let k_min = k_min.max(1);
+    let total_nodes: usize = distribution.iter().filter(|&(&degree, _)| degree >= k_min).map(|(_, &count)| count).sum();
+    if total_nodes == 0 {
+        return 0.0;
+    }
+    let mut observed: Vec<(usize, f64)> = distribution
+        .iter()
+        .filter(|&(&degree, _)| degree >= k_min)
+        .map(|(&degree, &count)| (degree, count as f64 / total_nodes as f64))
+        .collect();
+    observed.sort_by_key(|&(degree, _)| degree);
+
+
+    let mut theoretical: Vec<f64> = Vec::new();
+    let normalization: f64 = observed.iter().map(|(degree, _)| 1.0 / (*degree as f64).powf(alpha)).sum();
+    for (degree, _) in &observed {
+        theoretical.push(1.0 / (*degree as f64).powf(alpha) / normalization);
+    }
+
+
+    let mse: f64 = observed
+        .iter()
+        .zip(theoretical.iter())
+        .map(|((_, obs_prob), theo_prob)| (obs_prob - theo_prob).powi(2))
+        .sum();
+
+    1.0 / (1.0 + mse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_power_law_exponent() {
+        // Only the degree-3 nodes contribute to the log sum.
+        let mut distribution = HashMap::new();
+        distribution.insert(1, 10);
+        distribution.insert(3, 10);
+        let expected = 1.0 + 20.0 / (10.0 * 3f64.ln());
+        assert!((fit_power_law_exponent(&distribution).unwrap() - expected).abs() < 1e-12);
+
+        assert_eq!(fit_power_law_exponent(&HashMap::new()), None);
+        assert_eq!(fit_power_law_exponent(&HashMap::from([(0, 4)])), None);
+    }
+
+    #[test]
+    fn test_equal_degrees_have_no_fit() {
+        // Every node of a cycle has degree 2, so ln(k / k_min) sums to zero.
+        let distribution = HashMap::from([(0, 3), (2, 5)]);
+        assert_eq!(fit_power_law_exponent(&distribution), None);
+        assert_eq!(evaluate_fitted_power_law(&distribution), None);
+    }
+
+    #[test]
+    fn test_evaluate_fitted_power_law() {
+        let distribution = HashMap::from([(1, 100), (2, 18), (4, 3), (8, 1)]);
+        let (alpha, score) = evaluate_fitted_power_law(&distribution).unwrap();
+        assert_eq!(Some(alpha), fit_power_law_exponent(&distribution));
+        assert!(score > 0.0 && score <= 1.0);
+    }
+
+    #[test]
+    fn test_evaluate_power_law_with_k_min() {
+        let distribution = HashMap::from([(0, 7), (1, 50), (2, 9), (4, 1)]);
+        assert_eq!(evaluate_power_law(&distribution), evaluate_power_law_with(&distribution, 2.5, 1));
+
+        // Above k_min = 2 only degrees 2 and 4 remain, 9 : 1 against 4 : 1 expected at alpha = 2.
+        let observed = [0.9, 0.1];
+        let expected = [0.8, 0.2];
+        let mse: f64 = observed.iter().zip(expected).map(|(o, e)| (o - e) * (o - e)).sum();
+        assert!((evaluate_power_law_with(&distribution, 2.0, 2) - 1.0 / (1.0 + mse)).abs() < 1e-12);
+
+        assert_eq!(evaluate_power_law_with(&distribution, 2.5, 10), 0.0);
+    }
+
+    #[test]
+    fn test_ks_statistic() {
+        // At alpha = 2, P(1) = 0.8 and P(2) = 0.2 on the range 1..=2.
+        assert!(ks_statistic(&HashMap::from([(1, 4), (2, 1)]), 2.0).abs() < 1e-12);
+        assert!((ks_statistic(&HashMap::from([(0, 3), (1, 1), (2, 1)]), 2.0) - 0.3).abs() < 1e-12);
+        assert!(ks_statistic(&HashMap::from([(0, 3)]), 2.0).is_nan());
+    }
+}
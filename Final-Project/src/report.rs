@@ -0,0 +1,107 @@
+use std::fmt;
+use std::hash::Hash;
+
+use serde::Serialize;
+
+use crate::graph::Graph;
+use crate::power_law::{evaluate_fitted_power_law, ks_statistic};
+
+
+/// Headline statistics of a graph, gathered by `Graph::compute_report`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct MetricsReport {
+    pub node_count: usize,
+    pub edge_count: usize,
+    pub density: f64,
+    pub component_count: usize,
+    /// Mean of `degree` over all nodes; 0.0 for an empty graph.
+    pub average_degree: f64,
+    /// Maximum-likelihood power-law exponent, from `fit_power_law_exponent`.
+    /// `None`, like the two fields below, when the degrees vary too little to
+    /// fit one: no positive degree, or all equal to the smallest.
+    pub power_law_alpha: Option<f64>,
+    /// `evaluate_power_law_with` score at that exponent, in `(0, 1]`.
+    pub power_law_fit: Option<f64>,
+    /// Kolmogorov-Smirnov distance to that power law.
+    pub ks_distance: Option<f64>,
+}
+
+impl fmt::Display for MetricsReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Nodes: {}", self.node_count)?;
+        writeln!(f, "Edges: {}", self.edge_count)?;
+        writeln!(f, "Density: {:.6}", self.density)?;
+        writeln!(f, "Connected components: {}", self.component_count)?;
+        writeln!(f, "Average degree: {:.2}", self.average_degree)?;
+        match (self.power_law_alpha, self.power_law_fit, self.ks_distance) {
+            (Some(alpha), Some(fit), Some(ks_distance)) => {
+                writeln!(f, "Power-law exponent: alpha = {:.2}", alpha)?;
+                writeln!(f, "Power-law fit: {:.2}", fit)?;
+                write!(f, "Kolmogorov-Smirnov distance: D = {:.3}", ks_distance)
+            }
+            _ => write!(f, "Power-law fit: not enough degree variation to fit"),
+        }
+    }
+}
+
+
+impl<T: Eq + Hash + Clone> Graph<T> {
+    /// Computes every field of a `MetricsReport` in one call.
+    pub fn compute_report(&self) -> MetricsReport {
+        let distribution = self.degree_distribution();
+        let fitted = evaluate_fitted_power_law(&distribution);
+        MetricsReport {
+            node_count: self.node_count(),
+            edge_count: self.edge_count(),
+            density: self.density(),
+            component_count: self.component_count(),
+            average_degree: self.degree_stats().map_or(0.0, |degrees| degrees.mean),
+            power_law_alpha: fitted.map(|(alpha, _)| alpha),
+            power_law_fit: fitted.map(|(_, fit)| fit),
+            ks_distance: fitted.map(|(alpha, _)| ks_statistic(&distribution, alpha)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_report() {
+        let mut graph = Graph::new();
+        for leaf in ["A", "B", "C"] {
+            graph.add_edge("Hub".to_string(), leaf.to_string());
+        }
+        graph.add_edge("X".to_string(), "Y".to_string());
+
+        let report = graph.compute_report();
+        assert_eq!((report.node_count, report.edge_count, report.component_count), (6, 4, 2));
+        assert!((report.density - 4.0 / 15.0).abs() < 1e-12);
+        assert!((report.average_degree - 8.0 / 6.0).abs() < 1e-12);
+        let (alpha, fit) = evaluate_fitted_power_law(&graph.degree_distribution()).unwrap();
+        assert_eq!((report.power_law_alpha, report.power_law_fit), (Some(alpha), Some(fit)));
+
+        let text = report.to_string();
+        assert!(text.starts_with("Nodes: 6\nEdges: 4\n"));
+        assert!(text.contains("Connected components: 2\n"));
+        assert_eq!(Graph::<String>::new().compute_report().average_degree, 0.0);
+    }
+
+    #[test]
+    fn test_report_without_degree_variation_has_no_fit() {
+        let mut cycle = Graph::new();
+        for (a, b) in [("A", "B"), ("B", "C"), ("C", "D"), ("D", "A")] {
+            cycle.add_edge(a.to_string(), b.to_string());
+        }
+        let mut edgeless = Graph::new();
+        edgeless.add_edge("A".to_string(), "B".to_string());
+        edgeless.remove_edge(&"A".to_string(), &"B".to_string());
+
+        for graph in [cycle, edgeless] {
+            let report = graph.compute_report();
+            assert_eq!((report.power_law_alpha, report.power_law_fit, report.ks_distance), (None, None, None));
+            assert!(report.to_string().ends_with("Power-law fit: not enough degree variation to fit"));
+        }
+    }
+}
@@ -0,0 +1,63 @@
+/// Small seeded generator (SplitMix64) for the randomized algorithms, so
+/// results are reproducible without pulling in an RNG crate.
+#[derive(Debug, Clone)]
+pub(crate) struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub(crate) fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+
+    /// Uniform index in `0..bound`; `bound` must be positive.
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+
+
+    /// Uniform float in `[0, 1)`, from the top 53 bits.
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+
+    /// Fisher-Yates shuffle in place.
+    pub(crate) fn shuffle<X>(&mut self, items: &mut [X]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_is_seeded() {
+        let first: Vec<u64> = (0..4).scan(Rng::new(7), |rng, _| Some(rng.next_u64())).collect();
+        let second: Vec<u64> = (0..4).scan(Rng::new(7), |rng, _| Some(rng.next_u64())).collect();
+        assert_eq!(first, second);
+        assert_ne!(first, (0..4).scan(Rng::new(8), |rng, _| Some(rng.next_u64())).collect::<Vec<u64>>());
+
+        let mut rng = Rng::new(1);
+        assert!((0..100).all(|_| rng.below(3) < 3));
+        assert!((0..100).map(|_| rng.next_f64()).all(|x| (0.0..1.0).contains(&x)));
+
+        let mut items: Vec<u32> = (0..10).collect();
+        rng.shuffle(&mut items);
+        items.sort_unstable();
+        assert_eq!(items, (0..10).collect::<Vec<u32>>());
+    }
+}
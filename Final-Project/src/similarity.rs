@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::graph::Graph;
+
+
+impl<T: Eq + Hash + Clone> Graph<T> {
+    /// Ids adjacent to both `a` and `b`; empty if either node is unknown.
+    fn common_neighbor_ids(&self, a: &T, b: &T) -> Vec<u32> {
+        match (self.id(a), self.id(b)) {
+            (Some(a), Some(b)) => {
+                let b_neighbors = self.neighbor_ids(b);
+                self.neighbor_ids(a).iter().copied().filter(|neighbor| b_neighbors.contains(neighbor)).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+
+    pub fn common_neighbors(&self, a: &T, b: &T) -> HashSet<T> {
+        self.common_neighbor_ids(a, b).into_iter().map(|id| self.label(id).clone()).collect()
+    }
+
+
+    /// Shared neighbors over the union of both neighbor sets; 0.0 when either
+    /// node is unknown or the union is empty.
+    pub fn jaccard_similarity(&self, a: &T, b: &T) -> f64 {
+        let shared = self.common_neighbor_ids(a, b).len();
+        let union = self.degree(a) + self.degree(b) - shared;
+        if shared == 0 || union == 0 {
+            return 0.0;
+        }
+        shared as f64 / union as f64
+    }
+
+
+    /// Adamic-Adar index: the sum of `1 / ln(degree(z))` over the shared
+    /// neighbors `z`. Degree-1 neighbors would divide by zero and are
+    /// skipped; 0.0 with no shared neighbors.
+    pub fn adamic_adar(&self, a: &T, b: &T) -> f64 {
+        self.common_neighbor_ids(a, b)
+            .into_iter()
+            .map(|id| self.neighbor_ids(id).len())
+            .filter(|&degree| degree > 1)
+            .map(|degree| 1.0 / (degree as f64).ln())
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_common_neighbors_and_jaccard() {
+        let mut graph = Graph::new();
+        for (a, b) in [("A", "X"), ("A", "Y"), ("A", "Z"), ("B", "X"), ("B", "Y"), ("C", "D")] {
+            graph.add_edge(a.to_string(), b.to_string());
+        }
+        let (a, b) = ("A".to_string(), "B".to_string());
+
+        let shared: HashSet<String> = ["X", "Y"].iter().map(|node| node.to_string()).collect();
+        assert_eq!(graph.common_neighbors(&a, &b), shared);
+        assert!((graph.jaccard_similarity(&a, &b) - 2.0 / 3.0).abs() < 1e-12);
+
+        assert_eq!(graph.jaccard_similarity(&a, &"C".to_string()), 0.0);
+        assert_eq!(graph.jaccard_similarity(&a, &"Nobody".to_string()), 0.0);
+        assert!(graph.common_neighbors(&a, &"Nobody".to_string()).is_empty());
+    }
+
+    #[test]
+    fn test_adamic_adar() {
+        let mut graph = Graph::new();
+        for (a, b) in [("A", "X"), ("B", "X"), ("A", "Y"), ("B", "Y"), ("C", "Y")] {
+            graph.add_edge(a.to_string(), b.to_string());
+        }
+        let (a, b) = ("A".to_string(), "B".to_string());
+
+        let expected = 1.0 / 2f64.ln() + 1.0 / 3f64.ln();
+        assert!((graph.adamic_adar(&a, &b) - expected).abs() < 1e-12);
+        assert_eq!(graph.adamic_adar(&"C".to_string(), &"X".to_string()), 0.0);
+
+        // A node compared with itself shares its leaf neighbor, which has degree 1.
+        graph.add_edge("D".to_string(), "Leaf".to_string());
+        assert_eq!(graph.adamic_adar(&"D".to_string(), &"D".to_string()), 0.0);
+    }
+}
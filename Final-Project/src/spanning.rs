@@ -0,0 +1,132 @@
+use std::hash::Hash;
+
+use crate::graph::{weight_key, Graph};
+
+
+/// Disjoint-set forest over node ids with path halving and union by size.
+struct UnionFind {
+    parent: Vec<u32>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(n: usize) -> Self {
+        UnionFind { parent: (0..n as u32).collect(), size: vec![1; n] }
+    }
+
+
+    fn find(&mut self, mut id: u32) -> u32 {
+        while self.parent[id as usize] != id {
+            self.parent[id as usize] = self.parent[self.parent[id as usize] as usize];
+            id = self.parent[id as usize];
+        }
+        id
+    }
+
+
+    /// Joins the sets holding `a` and `b`; false if they were already one set.
+    fn union(&mut self, a: u32, b: u32) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a as usize] < self.size[b as usize] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b as usize] = a;
+        self.size[a as usize] += self.size[b as usize];
+        true
+    }
+}
+
+
+impl<T: Eq + Hash + Clone> Graph<T> {
+    /// Sum of all edge weights, with unweighted edges counting 1.0 each.
+    /// Parallel copies in a multigraph are not counted again.
+    pub fn total_weight(&self) -> f64 {
+        let mut total = 0.0;
+        for (id, neighbors) in self.adjacency.iter().enumerate() {
+            for &neighbor in neighbors.iter().filter(|&&neighbor| neighbor as usize >= id) {
+                total += self.weight_of(id as u32, neighbor);
+            }
+        }
+        total
+    }
+}
+
+
+impl<T: Eq + Hash + Clone + Ord> Graph<T> {
+    /// Minimum spanning forest by Kruskal's algorithm: every node, and one
+    /// tree of minimum total weight per connected component. Unweighted edges
+    /// weigh 1.0, equal weights are broken by the sorted endpoint names, and
+    /// self-loops are never kept. Node attributes and explicit weights carry
+    /// over; the result is never a multigraph.
+    pub fn minimum_spanning_tree(&self) -> Graph<T> {
+        let mut candidates: Vec<(f64, &T, &T, u32, u32)> = Vec::new();
+        for (id, neighbors) in self.adjacency.iter().enumerate() {
+            let id = id as u32;
+            for &neighbor in neighbors {
+                let (node, other) = (self.label(id), self.label(neighbor));
+                if node < other {
+                    let weight = self.weight_of(id, neighbor);
+                    candidates.push((weight, node, other, id, neighbor));
+                }
+            }
+        }
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0).then_with(|| (a.1, a.2).cmp(&(b.1, b.2))));
+
+        let mut tree = Graph { nodes: self.nodes.clone(), ids: self.ids.clone(), ..Graph::new() };
+        tree.adjacency = vec![Default::default(); self.node_count()];
+        tree.attributes = self.attributes.clone();
+
+        let mut forest = UnionFind::new(self.node_count());
+        for (_, _, _, id, neighbor) in candidates {
+            if forest.union(id, neighbor) {
+                tree.adjacency[id as usize].insert(neighbor);
+                tree.adjacency[neighbor as usize].insert(id);
+                if let Some(&weight) = self.weights.get(&weight_key(id, neighbor)) {
+                    tree.weights.insert(weight_key(id, neighbor), weight);
+                }
+            }
+        }
+        tree
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimum_spanning_tree() {
+        let mut graph = Graph::new();
+        graph.add_weighted_edge("A".to_string(), "B".to_string(), 4.0);
+        graph.add_weighted_edge("B".to_string(), "C".to_string(), 1.0);
+        graph.add_weighted_edge("A".to_string(), "C".to_string(), 2.0);
+        graph.add_weighted_edge("C".to_string(), "D".to_string(), 5.0);
+        graph.add_weighted_edge("B".to_string(), "D".to_string(), 3.0);
+        graph.add_weighted_edge("D".to_string(), "D".to_string(), 0.5);
+        graph.add_edge("X".to_string(), "Y".to_string());
+        graph.add_edge("Z".to_string(), "Z".to_string());
+
+        let tree = graph.minimum_spanning_tree();
+        let edges: Vec<(String, String)> = tree.edges().collect();
+        let expected = [("A", "C"), ("B", "C"), ("B", "D"), ("X", "Y")];
+        assert_eq!(edges, expected.map(|(a, b)| (a.to_string(), b.to_string())));
+        assert_eq!(tree.node_count(), 7);
+        assert_eq!(tree.total_weight(), 7.0);
+        assert_eq!(tree.edge_weight(&"B".to_string(), &"D".to_string()), Some(3.0));
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree_breaks_ties_by_name() {
+        let mut square = Graph::new();
+        for (a, b) in [("C", "D"), ("B", "C"), ("A", "B"), ("D", "A")] {
+            square.add_edge(a.to_string(), b.to_string());
+        }
+        let edges: Vec<(String, String)> = square.minimum_spanning_tree().edges().collect();
+        let expected = [("A", "B"), ("A", "D"), ("B", "C")];
+        assert_eq!(edges, expected.map(|(a, b)| (a.to_string(), b.to_string())));
+        assert_eq!(square.total_weight(), 4.0);
+    }
+}
@@ -0,0 +1,326 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use crate::graph::{weight_key, Graph};
+
+
+impl<T: Eq + Hash + Clone> Graph<T> {
+    pub fn is_bipartite(&self) -> bool {
+        self.bipartite_sets().is_some()
+    }
+
+
+    /// The two sides of a BFS two-coloring covering every component, or
+    /// `None` if some component has an odd cycle (a self-loop included).
+    pub fn bipartite_sets(&self) -> Option<(HashSet<T>, HashSet<T>)> {
+        let mut colors: Vec<Option<bool>> = vec![None; self.node_count()];
+
+        for start in 0..self.node_count() as u32 {
+            if colors[start as usize].is_some() {
+                continue;
+            }
+            colors[start as usize] = Some(false);
+            let mut queue = VecDeque::new();
+            queue.push_back(start);
+            while let Some(current) = queue.pop_front() {
+                let color = colors[current as usize] == Some(true);
+                for &neighbor in self.neighbor_ids(current) {
+                    match colors[neighbor as usize] {
+                        Some(neighbor_color) if neighbor_color == color => return None,
+                        Some(_) => {}
+                        None => {
+                            colors[neighbor as usize] = Some(!color);
+                            queue.push_back(neighbor);
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut left = HashSet::new();
+        let mut right = HashSet::new();
+        for (node, color) in self.nodes.iter().zip(colors) {
+            if color == Some(true) {
+                right.insert(node.clone());
+            } else {
+                left.insert(node.clone());
+            }
+        }
+        Some((left, right))
+    }
+
+
+    /// True if any component contains a cycle. Runs an iterative DFS that
+    /// ignores the edge back to each node's parent; a self-loop is a cycle.
+    pub fn has_cycle(&self) -> bool {
+        let mut visited = vec![false; self.node_count()];
+
+        for start in 0..self.node_count() as u32 {
+            if visited[start as usize] {
+                continue;
+            }
+            visited[start as usize] = true;
+            let mut stack: Vec<(u32, Option<u32>)> = vec![(start, None)];
+            while let Some((current, parent)) = stack.pop() {
+                for &neighbor in self.neighbor_ids(current) {
+                    if Some(neighbor) == parent {
+                        continue;
+                    }
+                    if visited[neighbor as usize] {
+                        return true;
+                    }
+                    visited[neighbor as usize] = true;
+                    stack.push((neighbor, Some(current)));
+                }
+            }
+        }
+
+        false
+    }
+
+
+    pub fn is_forest(&self) -> bool {
+        !self.has_cycle()
+    }
+
+
+    /// Iterative DFS over every component recording, per node id, its
+    /// discovery time, its low-link (the earliest discovery time reachable
+    /// through its subtree plus one back edge) and its DFS parent.
+    /// Self-loops are ignored.
+    fn low_links(&self) -> (Vec<usize>, Vec<usize>, Vec<Option<u32>>) {
+        let n = self.node_count();
+        let mut discovery: Vec<Option<usize>> = vec![None; n];
+        let mut low = vec![0; n];
+        let mut parent: Vec<Option<u32>> = vec![None; n];
+        let mut time = 0;
+
+        for root in 0..n as u32 {
+            if discovery[root as usize].is_some() {
+                continue;
+            }
+            discovery[root as usize] = Some(time);
+            low[root as usize] = time;
+            time += 1;
+            let mut stack: Vec<(u32, Vec<u32>)> = vec![(root, self.neighbor_ids(root).iter().copied().collect())];
+
+            while let Some((current, pending)) = stack.last_mut() {
+                let current = *current;
+                match pending.pop() {
+                    Some(neighbor) if neighbor == current => {}
+                    Some(neighbor) => match discovery[neighbor as usize] {
+                        None => {
+                            discovery[neighbor as usize] = Some(time);
+                            low[neighbor as usize] = time;
+                            time += 1;
+                            parent[neighbor as usize] = Some(current);
+                            stack.push((neighbor, self.neighbor_ids(neighbor).iter().copied().collect()));
+                        }
+                        Some(seen) if parent[current as usize] != Some(neighbor) => {
+                            low[current as usize] = low[current as usize].min(seen);
+                        }
+                        Some(_) => {}
+                    },
+                    None => {
+                        stack.pop();
+                        if let Some(up) = parent[current as usize] {
+                            low[up as usize] = low[up as usize].min(low[current as usize]);
+                        }
+                    }
+                }
+            }
+        }
+
+        (discovery.into_iter().flatten().collect(), low, parent)
+    }
+
+
+    /// Nodes whose removal splits their component into more pieces, found
+    /// with Tarjan's low-link DFS.
+    pub fn articulation_points(&self) -> HashSet<T> {
+        let (discovery, low, parent) = self.low_links();
+        let mut is_cut = vec![false; self.node_count()];
+        let mut root_children = vec![0; self.node_count()];
+
+        for (child, up) in parent.iter().enumerate() {
+            let Some(up) = *up else {
+                continue;
+            };
+            let up = up as usize;
+            if parent[up].is_none() {
+                root_children[up] += 1;
+            } else if low[child] >= discovery[up] {
+                is_cut[up] = true;
+            }
+        }
+
+        (0..self.node_count())
+            .filter(|&id| is_cut[id] || root_children[id] > 1)
+            .map(|id| self.label(id as u32).clone())
+            .collect()
+    }
+}
+
+
+impl<T: Eq + Hash + Clone + Ord> Graph<T> {
+    /// Edges whose removal disconnects their endpoints, found with the same
+    /// low-link DFS as `articulation_points`. Each bridge is reported once as
+    /// `(smaller, larger)`, sorted. A parallel edge in a multigraph is never a
+    /// bridge.
+    pub fn bridges(&self) -> Vec<(T, T)> {
+        let (discovery, low, parent) = self.low_links();
+        let mut bridges: Vec<(T, T)> = parent
+            .iter()
+            .enumerate()
+            .filter_map(|(child, up)| up.map(|up| (child as u32, up)))
+            .filter(|&(child, up)| low[child as usize] > discovery[up as usize])
+            .filter(|&(child, up)| !self.multiplicities.contains_key(&weight_key(child, up)))
+            .map(|(child, up)| {
+                let (child, up) = (self.label(child).clone(), self.label(up).clone());
+                if child < up {
+                    (child, up)
+                } else {
+                    (up, child)
+                }
+            })
+            .collect();
+        bridges.sort();
+        bridges
+    }
+
+
+    /// Welsh-Powell greedy coloring: nodes are taken by descending degree,
+    /// ties in sorted order, and each gets the smallest color no neighbor
+    /// holds. Colors run from 0, so the number used is one more than the
+    /// largest value. Self-loops are ignored.
+    pub fn greedy_coloring(&self) -> HashMap<T, usize> {
+        let mut order = self.sorted_ids();
+        order.sort_by_key(|&id| Reverse(self.neighbor_ids(id).len()));
+
+        let mut colors: Vec<Option<usize>> = vec![None; self.node_count()];
+        for id in order {
+            let taken: HashSet<usize> = self
+                .neighbor_ids(id)
+                .iter()
+                .filter(|&&neighbor| neighbor != id)
+                .filter_map(|&neighbor| colors[neighbor as usize])
+                .collect();
+            colors[id as usize] = (0..).find(|color| !taken.contains(color));
+        }
+
+        colors.into_iter().enumerate().map(|(id, color)| (self.label(id as u32).clone(), color.unwrap())).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bipartite() {
+        let mut graph = Graph::new();
+        graph.add_edge("Alice".to_string(), "Shop".to_string());
+        graph.add_edge("Bob".to_string(), "Shop".to_string());
+        graph.add_edge("Bob".to_string(), "Cafe".to_string());
+        graph.add_edge("X".to_string(), "Y".to_string());
+        assert!(graph.is_bipartite());
+
+        let (left, right) = graph.bipartite_sets().unwrap();
+        assert_eq!(left.len() + right.len(), 6);
+        assert_eq!(left.contains("Shop"), left.contains("Cafe"));
+        assert_ne!(left.contains("Shop"), left.contains("Bob"));
+        assert_ne!(left.contains("X"), left.contains("Y"));
+
+        // An odd cycle in a second component must still be found.
+        graph.add_edge("P".to_string(), "Q".to_string());
+        graph.add_edge("Q".to_string(), "R".to_string());
+        graph.add_edge("R".to_string(), "P".to_string());
+        assert!(!graph.is_bipartite());
+        assert!(graph.bipartite_sets().is_none());
+    }
+
+    #[test]
+    fn test_has_cycle() {
+        let mut graph = Graph::new();
+        graph.add_edge("A".to_string(), "B".to_string());
+        graph.add_edge("B".to_string(), "C".to_string());
+        graph.add_edge("B".to_string(), "D".to_string());
+        graph.add_edge("X".to_string(), "Y".to_string());
+        assert!(!graph.has_cycle());
+        assert!(graph.is_forest());
+
+        graph.add_edge("Y".to_string(), "Z".to_string());
+        graph.add_edge("Z".to_string(), "X".to_string());
+        assert!(graph.has_cycle());
+
+        let mut looped = Graph::new();
+        looped.add_edge("A".to_string(), "A".to_string());
+        assert!(looped.has_cycle());
+        assert!(!Graph::<String>::new().has_cycle());
+    }
+
+    fn graph_of(pairs: &[(&str, &str)]) -> Graph {
+        Graph::from_edges(pairs.iter().map(|(a, b)| (a.to_string(), b.to_string())))
+    }
+
+    #[test]
+    fn test_articulation_points() {
+        // Two triangles joined through the bridge C-D, plus a pendant E on D
+        // and a self-loop that must not matter.
+        let graph = graph_of(&[
+            ("A", "B"),
+            ("B", "C"),
+            ("C", "A"),
+            ("C", "D"),
+            ("D", "F"),
+            ("F", "G"),
+            ("G", "D"),
+            ("D", "E"),
+            ("A", "A"),
+        ]);
+        let expected: HashSet<String> = ["C", "D"].iter().map(|node| node.to_string()).collect();
+        assert_eq!(graph.articulation_points(), expected);
+
+        let path = graph_of(&[("A", "B"), ("B", "C"), ("X", "Y")]);
+        assert_eq!(path.articulation_points(), HashSet::from(["B".to_string()]));
+        assert!(graph_of(&[("A", "B"), ("B", "C"), ("C", "A")]).articulation_points().is_empty());
+    }
+
+    #[test]
+    fn test_bridges() {
+        let tree = graph_of(&[("B", "A"), ("B", "C"), ("D", "B"), ("C", "E")]);
+        let pairs = |edges: &[(&str, &str)]| -> Vec<(String, String)> {
+            edges.iter().map(|(a, b)| (a.to_string(), b.to_string())).collect()
+        };
+        assert_eq!(tree.bridges(), pairs(&[("A", "B"), ("B", "C"), ("B", "D"), ("C", "E")]));
+
+        let mut triangles = graph_of(&[("A", "B"), ("B", "C"), ("C", "A"), ("D", "C"), ("D", "E"), ("E", "F"), ("F", "D")]);
+        assert_eq!(triangles.bridges(), pairs(&[("C", "D")]));
+        triangles.add_edge("E".to_string(), "E".to_string());
+        assert_eq!(triangles.bridges(), pairs(&[("C", "D")]));
+
+        let mut doubled = Graph::new_multigraph();
+        doubled.add_edges(&[("A".to_string(), "B".to_string()), ("A".to_string(), "B".to_string())]);
+        doubled.add_edge("B".to_string(), "C".to_string());
+        assert_eq!(doubled.bridges(), pairs(&[("B", "C")]));
+    }
+
+    #[test]
+    fn test_greedy_coloring() {
+        // A wheel: hub H around an odd rim needs four colors.
+        let mut graph = graph_of(&[("A", "B"), ("B", "C"), ("C", "D"), ("D", "E"), ("E", "A"), ("X", "X")]);
+        for rim in ["A", "B", "C", "D", "E"] {
+            graph.add_edge("H".to_string(), rim.to_string());
+        }
+
+        let colors = graph.greedy_coloring();
+        assert_eq!(colors.len(), graph.node_count());
+        for (a, b) in graph.edges().filter(|(a, b)| a != b) {
+            assert_ne!(colors[&a], colors[&b], "{} and {} share a color", a, b);
+        }
+        assert_eq!(colors["H"], 0);
+        assert_eq!(colors.values().max().map(|&color| color + 1), Some(4));
+        assert!(Graph::<String>::new().greedy_coloring().is_empty());
+    }
+}
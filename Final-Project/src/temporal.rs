@@ -0,0 +1,63 @@
+use std::hash::Hash;
+
+use crate::graph::Graph;
+
+
+/// Undirected edges stamped with the time they appeared, e.g. transaction
+/// times, so the network can be rebuilt as it stood at any moment.
+#[derive(Clone)]
+pub struct TemporalGraph<T = String> {
+    pub(crate) edges: Vec<(T, T, u64)>,
+}
+
+impl<T> Default for TemporalGraph<T> {
+    fn default() -> Self {
+        TemporalGraph { edges: Vec::new() }
+    }
+}
+
+impl<T: Eq + Hash + Clone> TemporalGraph<T> {
+    pub fn new() -> Self {
+        TemporalGraph::default()
+    }
+
+
+    pub fn add_edge(&mut self, node1: T, node2: T, timestamp: u64) {
+        self.edges.push((node1, node2, timestamp));
+    }
+
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+
+    /// The graph of every edge stamped at or before `up_to`. Nodes appear
+    /// with their first edge, and repeated edges collapse as in `Graph`.
+    pub fn snapshot(&self, up_to: u64) -> Graph<T> {
+        let mut graph = Graph::new();
+        for (node1, node2, _) in self.edges.iter().filter(|(_, _, timestamp)| *timestamp <= up_to) {
+            graph.add_edge(node1.clone(), node2.clone());
+        }
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot() {
+        let mut temporal = TemporalGraph::new();
+        temporal.add_edge("A".to_string(), "B".to_string(), 10);
+        temporal.add_edge("B".to_string(), "C".to_string(), 20);
+        temporal.add_edge("A".to_string(), "B".to_string(), 30);
+
+        assert_eq!(temporal.snapshot(5).node_count(), 0);
+        assert_eq!(temporal.snapshot(10).edge_count(), 1);
+        assert_eq!(temporal.snapshot(20).degree_distribution(), temporal.snapshot(30).degree_distribution());
+        assert_eq!(temporal.snapshot(u64::MAX).node_count(), 3);
+        assert_eq!(temporal.edge_count(), 3);
+    }
+}